@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Info {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WishlistSummary {
+    pub id: u32,
+    pub name: String,
+    pub game_id: u32,
+    #[serde(default)]
+    pub public: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RemoteWishlistItem {
+    pub quantity: u32,
+    pub meta_name: Option<String>,
+    pub expansion_code: Option<String>,
+    pub collector_number: Option<String>,
+    pub language: Option<String>,
+    pub condition: Option<String>,
+    pub foil: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RemoteWishlist {
+    pub id: u32,
+    pub name: String,
+    pub game_id: u32,
+    #[serde(default)]
+    pub items: Vec<RemoteWishlistItem>,
+}
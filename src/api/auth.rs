@@ -0,0 +1,24 @@
+use crate::error::AppError;
+use dotenv::dotenv;
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::env;
+
+pub fn get_auth_headers() -> Result<HeaderMap, AppError> {
+    dotenv().ok();
+    let mut headers = HeaderMap::new();
+    let auth_token =
+        env::var("CARD_TRADER_AUTH").map_err(|e| AppError::missing_env("CARD_TRADER_AUTH", e))?;
+    let cookie = env::var("CARD_TRADER_COOKIE")
+        .map_err(|e| AppError::missing_env("CARD_TRADER_COOKIE", e))?;
+    headers.insert(
+        "Authorization",
+        HeaderValue::from_str(&auth_token)
+            .map_err(|_| AppError::Auth("CARD_TRADER_AUTH is not a valid header".to_string()))?,
+    );
+    headers.insert(
+        "Cookie",
+        HeaderValue::from_str(&cookie)
+            .map_err(|_| AppError::Auth("CARD_TRADER_COOKIE is not a valid header".to_string()))?,
+    );
+    Ok(headers)
+}
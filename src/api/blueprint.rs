@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Blueprint {
+    pub id: u32,
+    pub name: String,
+    pub version: Option<String>,
+    pub collector_number: Option<String>,
+    pub expansion_name: String,
+    pub expansion_id: Option<u32>,
+    pub game_id: Option<u32>,
+    pub category_id: Option<u32>,
+    pub image_url: Option<String>,
+    pub rarity: Option<String>,
+    // Idiomas em que a carta pode ser anunciada
+    pub languages: Vec<String>,
+    // Se existe versão foil para anunciar
+    pub foil: Option<bool>,
+    pub scryfall_id: Option<String>,
+    pub tcgplayer_id: Option<u64>,
+    pub cardmarket_ids: Vec<u64>,
+}
+
+impl Blueprint {
+    pub fn into_data(self, expansion_name: &str) -> BlueprintData {
+        BlueprintData {
+            blueprint_id: self.id,
+            card_name: self.name,
+            version: self.version,
+            collector_number: self.collector_number.unwrap_or_else(|| "N/A".to_string()),
+            expansion_name: expansion_name.to_string(),
+            expansion_id: self.expansion_id,
+            expansion_code: None,
+            game_id: self.game_id,
+            category_id: self.category_id,
+            image_url: self.image_url,
+            rarity: self.rarity,
+            languages: self.languages,
+            foil: self.foil,
+            scryfall_id: self.scryfall_id,
+            tcgplayer_id: self.tcgplayer_id,
+            cardmarket_ids: self.cardmarket_ids,
+        }
+    }
+}
+
+// Registro salvo no cache. Os campos além da expansão vieram depois, por isso
+// têm valor padrão ao ler arquivos antigos.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BlueprintData {
+    pub blueprint_id: u32,
+    pub card_name: String,
+    pub version: Option<String>,
+    pub collector_number: String,
+    pub expansion_name: String,
+    #[serde(default)]
+    pub expansion_id: Option<u32>,
+    #[serde(default)]
+    pub expansion_code: Option<String>,
+    #[serde(default)]
+    pub game_id: Option<u32>,
+    #[serde(default)]
+    pub category_id: Option<u32>,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub rarity: Option<String>,
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub foil: Option<bool>,
+    #[serde(default)]
+    pub scryfall_id: Option<String>,
+    #[serde(default)]
+    pub tcgplayer_id: Option<u64>,
+    #[serde(default)]
+    pub cardmarket_ids: Vec<u64>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ImageVariant {
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BlueprintImage {
+    pub url: Option<String>,
+    #[serde(default)]
+    pub show: Option<ImageVariant>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EditableProperty {
+    pub name: String,
+    #[serde(default)]
+    pub possible_values: Vec<Value>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BlueprintApiResponse {
+    pub id: u32,
+    pub name: String,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub game_id: Option<u32>,
+    #[serde(default)]
+    pub category_id: Option<u32>,
+    #[serde(default)]
+    pub expansion_id: Option<u32>,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub image: Option<BlueprintImage>,
+    #[serde(default)]
+    pub fixed_properties: HashMap<String, Value>,
+    #[serde(default)]
+    pub editable_properties: Vec<EditableProperty>,
+    #[serde(default)]
+    pub scryfall_id: Option<String>,
+    #[serde(default)]
+    pub tcg_player_id: Option<u64>,
+    #[serde(default)]
+    pub card_market_ids: Vec<u64>,
+}
+
+// Como em properties_hash, os nomes dependem do jogo ("mtg_rarity",
+// "pokemon_rarity", "mtg_language", ...), então procuramos pelo sufixo.
+fn matches_property(key: &str, name: &str) -> bool {
+    key == name || key.ends_with(&format!("_{}", name))
+}
+
+impl BlueprintApiResponse {
+    fn fixed_str(&self, name: &str) -> Option<String> {
+        self.fixed_properties
+            .iter()
+            .find(|(key, _)| matches_property(key, name))
+            .and_then(|(_, value)| value.as_str())
+            .map(str::to_string)
+    }
+
+    fn editable(&self, name: &str) -> Option<&EditableProperty> {
+        self.editable_properties
+            .iter()
+            .find(|property| matches_property(&property.name, name))
+    }
+
+    pub fn into_blueprint(self) -> Blueprint {
+        let languages = self
+            .editable("language")
+            .map(|property| {
+                property
+                    .possible_values
+                    .iter()
+                    .filter_map(|value| value.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let foil = self.editable("foil").map(|property| {
+            property
+                .possible_values
+                .iter()
+                .any(|value| value.as_bool() == Some(true))
+        });
+        let image_url = self.image_url.clone().or_else(|| {
+            let image = self.image.as_ref()?;
+            image
+                .show
+                .as_ref()
+                .and_then(|show| show.url.clone())
+                .or_else(|| image.url.clone())
+        });
+
+        Blueprint {
+            collector_number: self.fixed_str("collector_number"),
+            rarity: self.fixed_str("rarity"),
+            id: self.id,
+            name: self.name,
+            version: self.version,
+            expansion_name: String::new(), // Preenchido por quem conhece a expansão
+            expansion_id: self.expansion_id,
+            game_id: self.game_id,
+            category_id: self.category_id,
+            image_url,
+            languages,
+            foil,
+            scryfall_id: self.scryfall_id,
+            tcgplayer_id: self.tcg_player_id,
+            cardmarket_ids: self.card_market_ids,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_record_keeps_game_rarity_languages_and_external_ids() {
+        let json = r#"{
+            "id": 1234,
+            "name": "Lightning Bolt",
+            "version": null,
+            "game_id": 1,
+            "category_id": 1,
+            "expansion_id": 56,
+            "image_url": "https://www.cardtrader.com/uploads/blueprints/image/1234/bolt.jpg",
+            "fixed_properties": {"collector_number": "146", "mtg_rarity": "Common"},
+            "editable_properties": [
+                {"name": "condition", "type": "string", "possible_values": ["Near Mint", "Played"]},
+                {"name": "mtg_language", "type": "string", "possible_values": ["en", "it", "pt"]},
+                {"name": "mtg_foil", "type": "boolean", "possible_values": [false, true]}
+            ],
+            "scryfall_id": "e3285e6b-3e79-4d7c-bf96-d920f973b80a",
+            "tcg_player_id": 33209,
+            "card_market_ids": [2430]
+        }"#;
+
+        let response: BlueprintApiResponse = serde_json::from_str(json).unwrap();
+        let data = response.into_blueprint().into_data("Magic 2011");
+
+        assert_eq!(data.collector_number, "146");
+        assert_eq!(data.rarity.as_deref(), Some("Common"));
+        assert_eq!(data.languages, vec!["en", "it", "pt"]);
+        assert_eq!(data.foil, Some(true));
+        assert_eq!((data.game_id, data.category_id), (Some(1), Some(1)));
+        assert_eq!(data.expansion_id, Some(56));
+        assert_eq!(data.tcgplayer_id, Some(33209));
+        assert_eq!(data.cardmarket_ids, vec![2430]);
+    }
+
+    #[test]
+    fn old_cache_records_still_load() {
+        let old = r#"{"blueprint_id": 1, "card_name": "Sol Ring", "version": null,
+            "collector_number": "1", "expansion_name": "Alpha"}"#;
+        let data: BlueprintData = serde_json::from_str(old).unwrap();
+        assert_eq!(data.expansion_id, None);
+        assert!(data.languages.is_empty());
+    }
+}
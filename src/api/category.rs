@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Category {
+    pub id: u32,
+    pub name: String,
+    pub game_id: u32,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Game {
+    pub id: u32,
+    pub name: String,
+    pub display_name: String,
+}
+
+impl Game {
+    // Aceita o id, o nome ("magic") ou o nome de exibição ("Magic: the Gathering")
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim();
+        query.parse::<u32>().is_ok_and(|id| id == self.id)
+            || self.name.eq_ignore_ascii_case(query)
+            || self.display_name.eq_ignore_ascii_case(query)
+    }
+}
+
+pub fn find_game<'a>(games: &'a [Game], query: &str) -> Option<&'a Game> {
+    games.iter().find(|game| game.matches(query))
+}
+
+#[derive(Deserialize)]
+pub(crate) struct GamesResponse {
+    pub array: Vec<Game>,
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Price {
+    pub cents: i64,
+    pub currency: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProductExpansion {
+    pub id: u32,
+    pub code: String,
+    pub name_en: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Seller {
+    pub id: u32,
+    pub username: String,
+    #[serde(default)]
+    pub can_sell_via_hub: bool,
+    pub country_code: Option<String>,
+    pub user_type: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MarketplaceProduct {
+    pub id: u64,
+    pub blueprint_id: u32,
+    pub name_en: String,
+    pub quantity: u32,
+    pub price: Price,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub properties_hash: HashMap<String, Value>,
+    pub expansion: ProductExpansion,
+    pub user: Seller,
+    #[serde(default)]
+    pub graded: Option<bool>,
+    #[serde(default)]
+    pub on_vacation: bool,
+}
+
+impl MarketplaceProduct {
+    // As chaves de properties_hash dependem do jogo ("mtg_language",
+    // "pokemon_language", ...), então procuramos pelo sufixo.
+    fn property(&self, name: &str) -> Option<&Value> {
+        self.properties_hash.get(name).or_else(|| {
+            self.properties_hash
+                .iter()
+                .find(|(key, _)| key.ends_with(&format!("_{}", name)))
+                .map(|(_, value)| value)
+        })
+    }
+
+    pub fn property_str(&self, name: &str) -> Option<String> {
+        self.property(name)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    }
+
+    pub fn property_bool(&self, name: &str) -> Option<bool> {
+        self.property(name).and_then(|value| value.as_bool())
+    }
+}
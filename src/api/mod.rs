@@ -0,0 +1,242 @@
+pub mod account;
+pub mod auth;
+pub mod blueprint;
+pub mod category;
+pub mod expansion;
+pub mod game;
+pub mod marketplace;
+
+use crate::error::AppError;
+use account::{Info, RemoteWishlist, WishlistSummary};
+use blueprint::{Blueprint, BlueprintApiResponse};
+use category::Category;
+use expansion::Expansion;
+use game::{Game, GamesResponse};
+use marketplace::MarketplaceProduct;
+use reqwest::{header::HeaderMap, Client, StatusCode};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::env;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.cardtrader.com/api/v2";
+
+// Cliente tipado da API v2 da CardTrader. A base URL é configurável para
+// permitir apontar para um servidor mock nos testes.
+#[derive(Clone)]
+pub struct CardTraderClient {
+    client: Client,
+    headers: HeaderMap,
+    base_url: String,
+}
+
+impl CardTraderClient {
+    pub fn new(client: Client, headers: HeaderMap) -> Self {
+        CardTraderClient {
+            client,
+            headers,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    // Usa CARD_TRADER_AUTH/CARD_TRADER_COOKIE e, se definida, CARD_TRADER_API_URL
+    pub fn from_env() -> Result<Self, AppError> {
+        let client = Client::builder().build()?;
+        let api = CardTraderClient::new(client, auth::get_auth_headers()?);
+        match env::var("CARD_TRADER_API_URL") {
+            Ok(base_url) => Ok(api.with_base_url(&base_url)),
+            Err(_) => Ok(api),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, AppError> {
+        let request = self
+            .client
+            .request(reqwest::Method::GET, format!("{}{}", self.base_url, path))
+            .headers(self.headers.clone())
+            .query(query);
+
+        let response = request.send().await?;
+        if matches!(
+            response.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) {
+            return Err(AppError::Auth(format!(
+                "CardTrader answered {}, check CARD_TRADER_AUTH",
+                response.status()
+            )));
+        }
+        let response = response.error_for_status()?;
+        let body = response.text().await?;
+        let parsed: T = serde_json::from_str(&body)?;
+        Ok(parsed)
+    }
+
+    pub async fn info(&self) -> Result<Info, AppError> {
+        self.get("/info", &[]).await
+    }
+
+    pub async fn games(&self) -> Result<Vec<Game>, AppError> {
+        let response: GamesResponse = self.get("/games", &[]).await?;
+        Ok(response.array)
+    }
+
+    pub async fn categories(&self, game_id: Option<u32>) -> Result<Vec<Category>, AppError> {
+        let query: Vec<(&str, String)> = game_id
+            .map(|id| vec![("game_id", id.to_string())])
+            .unwrap_or_default();
+        self.get("/categories", &query).await
+    }
+
+    pub async fn expansions(&self) -> Result<Vec<Expansion>, AppError> {
+        self.get("/expansions", &[]).await
+    }
+
+    pub async fn blueprints_export(&self, expansion_id: u32) -> Result<Vec<Blueprint>, AppError> {
+        let api_response: Vec<BlueprintApiResponse> = self
+            .get(
+                "/blueprints/export",
+                &[("expansion_id", expansion_id.to_string())],
+            )
+            .await?;
+
+        let blueprints: Vec<Blueprint> = api_response
+            .into_iter()
+            .map(BlueprintApiResponse::into_blueprint)
+            .collect();
+
+        Ok(blueprints)
+    }
+
+    pub async fn marketplace_products(
+        &self,
+        blueprint_id: u32,
+    ) -> Result<Vec<MarketplaceProduct>, AppError> {
+        let mut products: HashMap<String, Vec<MarketplaceProduct>> = self
+            .get(
+                "/marketplace/products",
+                &[("blueprint_id", blueprint_id.to_string())],
+            )
+            .await?;
+        Ok(products
+            .remove(&blueprint_id.to_string())
+            .unwrap_or_default())
+    }
+
+    pub async fn marketplace_products_by_expansion(
+        &self,
+        expansion_id: u32,
+    ) -> Result<HashMap<u32, Vec<MarketplaceProduct>>, AppError> {
+        let products: HashMap<String, Vec<MarketplaceProduct>> = self
+            .get(
+                "/marketplace/products",
+                &[("expansion_id", expansion_id.to_string())],
+            )
+            .await?;
+        Ok(products
+            .into_iter()
+            .filter_map(|(id, products)| id.parse().ok().map(|id| (id, products)))
+            .collect())
+    }
+
+    pub async fn wishlists(&self, game_id: Option<u32>) -> Result<Vec<WishlistSummary>, AppError> {
+        let query: Vec<(&str, String)> = game_id
+            .map(|id| vec![("game_id", id.to_string())])
+            .unwrap_or_default();
+        self.get("/wishlists", &query).await
+    }
+
+    pub async fn wishlist(&self, wishlist_id: u32) -> Result<RemoteWishlist, AppError> {
+        self.get(&format!("/wishlists/{}", wishlist_id), &[]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+
+    // Mock da API: responde uma requisição e devolve o que recebeu
+    async fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, handle)
+    }
+
+    fn client(url: &str) -> CardTraderClient {
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", HeaderValue::from_static("Bearer secret"));
+        headers.insert("Cookie", HeaderValue::from_static("session=1"));
+        CardTraderClient::new(Client::new(), headers).with_base_url(url)
+    }
+
+    #[tokio::test]
+    async fn marketplace_products_send_the_token_and_parse_the_listings() {
+        let body = r#"{"11": [{
+            "id": 1, "blueprint_id": 11, "name_en": "Sol Ring", "quantity": 2,
+            "price": {"cents": 350, "currency": "EUR"}, "description": null,
+            "properties_hash": {"condition": "Near Mint", "mtg_language": "en", "mtg_foil": false},
+            "expansion": {"id": 5, "code": "cmm", "name_en": "Commander Masters"},
+            "user": {"id": 9, "username": "shop", "can_sell_via_hub": true,
+                     "country_code": "IT", "user_type": "pro"},
+            "on_vacation": false
+        }]}"#;
+        let (url, request) = serve_once("200 OK", body).await;
+
+        let products = client(&url).marketplace_products(11).await.unwrap();
+
+        let request = request.await.unwrap().to_lowercase();
+        assert!(request.starts_with("get /marketplace/products?blueprint_id=11 "));
+        assert!(request.contains("authorization: bearer secret\r\n"));
+        assert!(request.contains("cookie: session=1\r\n"));
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].price.cents, 350);
+        assert_eq!(products[0].expansion.code, "cmm");
+        assert_eq!(products[0].property_str("language").as_deref(), Some("en"));
+        assert!(products[0].user.can_sell_via_hub);
+    }
+
+    #[tokio::test]
+    async fn rejected_tokens_are_auth_errors() {
+        for status in ["401 Unauthorized", "403 Forbidden"] {
+            let (url, _) = serve_once(status, r#"{"error": "nope"}"#).await;
+            let result = client(&url).info().await;
+            assert!(matches!(result, Err(AppError::Auth(_))), "{}", status);
+        }
+    }
+}
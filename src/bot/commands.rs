@@ -0,0 +1,728 @@
+use super::users::{self, BotUser};
+use crate::api::blueprint::BlueprintData;
+use crate::cache::BlueprintCache;
+use crate::error::AppError;
+use crate::history::PriceStats;
+use crate::notify::Channel;
+use crate::pricing::{format_amount, PriceSource};
+use crate::storage::Storage;
+use crate::tr;
+use crate::wishlist::{self, describe_item, ItemChanges, WishlistFilter, WishlistItem};
+use std::sync::Arc;
+use teloxide::types::BotCommand;
+use teloxide::utils::command::BotCommands;
+
+// Limite de botões numa escolha de versão
+const MAX_CHOICES: usize = 10;
+const HISTORY_LINES: usize = 10;
+
+// As descrições ficam nas mensagens traduzidas, em COMMANDS e ADMIN_COMMANDS
+#[derive(BotCommands, Clone, Debug, PartialEq)]
+#[command(rename_rule = "lowercase")]
+pub enum Command {
+    Help,
+    Add(String),
+    List,
+    Remove(String),
+    Target(String),
+    Check,
+    History(String),
+    Alerts(String),
+}
+
+#[derive(BotCommands, Clone, Debug, PartialEq)]
+#[command(rename_rule = "lowercase")]
+pub enum AdminCommand {
+    AddUser(String),
+    AddAdmin(String),
+    RemoveUser(String),
+    Users,
+}
+
+// Cada comando e a mensagem que o descreve
+const COMMANDS: [(&str, &str); 8] = [
+    ("help", "bot.help.help"),
+    ("add", "bot.help.add"),
+    ("list", "bot.help.list"),
+    ("remove", "bot.help.remove"),
+    ("target", "bot.help.target"),
+    ("check", "bot.help.check"),
+    ("history", "bot.help.history"),
+    ("alerts", "bot.help.alerts"),
+];
+
+const ADMIN_COMMANDS: [(&str, &str); 4] = [
+    ("adduser", "bot.help.adduser"),
+    ("addadmin", "bot.help.addadmin"),
+    ("removeuser", "bot.help.removeuser"),
+    ("users", "bot.help.users"),
+];
+
+// Menu de comandos do Telegram, no idioma configurado
+pub fn bot_commands() -> Vec<BotCommand> {
+    COMMANDS
+        .iter()
+        .map(|(command, key)| BotCommand::new(*command, tr!(key)))
+        .collect()
+}
+
+fn help(admin: bool) -> String {
+    let section = |title: &str, commands: &[(&str, &str)]| {
+        let lines: Vec<String> = commands
+            .iter()
+            .map(|(command, key)| format!("/{} — {}", command, tr!(key)))
+            .collect();
+        format!("{}\n\n{}", tr!(title), lines.join("\n"))
+    };
+    let mut help = section("bot.help.title", &COMMANDS);
+    if admin {
+        help = format!(
+            "{}\n\n{}",
+            help,
+            section("bot.help.admin_title", &ADMIN_COMMANDS)
+        );
+    }
+    help
+}
+
+// O que os comandos usam; os mesmos objetos da CLI. `list` é a wishlist do
+// dono do bot (TELEGRAM_CHAT_ID).
+pub struct BotContext {
+    pub storage: Arc<dyn Storage>,
+    pub cache: Arc<BlueprintCache>,
+    pub source: Arc<dyn PriceSource>,
+    pub list: String,
+    pub game_id: Option<u32>,
+    pub owner_chat_id: Option<i64>,
+}
+
+// Texto simples ou texto com botões (rótulo, dado do callback)
+#[derive(Debug, PartialEq)]
+pub enum Reply {
+    Text(String),
+    Choices(String, Vec<(String, String)>),
+}
+
+#[derive(Debug, PartialEq)]
+struct AddArgs {
+    card: String,
+    expansion: Option<String>,
+    target_price: Option<f64>,
+}
+
+fn parse_price(value: &str) -> Option<f64> {
+    value
+        .replace(',', ".")
+        .parse::<f64>()
+        .ok()
+        .filter(|price| price.is_finite() && *price >= 0.0)
+}
+
+// "Lightning Bolt (M11) 1.50": a expansão (nome ou código) vai entre
+// parênteses e o preço desejado fica no final
+fn parse_add(args: &str) -> Option<AddArgs> {
+    let args = args.trim();
+    if let Some((card, last)) = args.rsplit_once(' ') {
+        if let Some(price) = parse_price(last) {
+            let mut parsed = parse_card(card.trim_end())?;
+            parsed.target_price = Some(price);
+            return Some(parsed);
+        }
+    }
+    parse_card(args)
+}
+
+fn parse_card(args: &str) -> Option<AddArgs> {
+    let mut rest = args.trim();
+    let mut expansion = None;
+    if let Some(card) = rest.strip_suffix(')') {
+        if let Some((card, set)) = card.rsplit_once('(') {
+            expansion = Some(set.trim().to_string());
+            rest = card.trim_end();
+        }
+    }
+    if rest.is_empty() {
+        return None;
+    }
+    Some(AddArgs {
+        card: rest.to_string(),
+        expansion,
+        target_price: None,
+    })
+}
+
+// O número mostrado pela /list seleciona um item; um nome seleciona todos os
+// itens da carta
+fn select_items(items: &[WishlistItem], reference: &str) -> Option<WishlistFilter> {
+    let reference = reference.trim();
+    if reference.is_empty() {
+        return None;
+    }
+    if let Ok(number) = reference.parse::<usize>() {
+        let item = items.get(number.checked_sub(1)?)?;
+        return Some(WishlistFilter {
+            card_name: Some(item.card_name.clone()),
+            expansion_name: Some(item.expansion_name.clone()),
+            blueprint_id: item.blueprint_id,
+        });
+    }
+    Some(WishlistFilter {
+        card_name: Some(reference.to_string()),
+        ..WishlistFilter::default()
+    })
+}
+
+fn add_callback(blueprint_id: u32, target_price: Option<f64>) -> String {
+    match target_price {
+        Some(price) => format!("add:{}:{}", blueprint_id, price),
+        None => format!("add:{}", blueprint_id),
+    }
+}
+
+fn version_label(blueprint: &BlueprintData) -> String {
+    format!(
+        "{} ({}) - {}",
+        blueprint.card_name, blueprint.collector_number, blueprint.expansion_name
+    )
+}
+
+// Os comandos usam a wishlist do chat que os enviou
+pub async fn handle_command(
+    context: &BotContext,
+    user: &BotUser,
+    command: Command,
+) -> Result<Reply, AppError> {
+    let list = user.wishlist.as_str();
+    match command {
+        Command::Help => Ok(Reply::Text(help(user.admin))),
+        Command::Add(args) => add(context, list, &args).await,
+        Command::List => show(context, list).map(Reply::Text),
+        Command::Remove(args) => remove(context, list, &args).map(Reply::Text),
+        Command::Target(args) => target(context, list, &args).map(Reply::Text),
+        Command::Check => check(context, list).await.map(Reply::Text),
+        Command::History(args) => history(context, list, &args).map(Reply::Text),
+        Command::Alerts(args) => alerts(context, user, &args).map(Reply::Text),
+    }
+}
+
+// Só para administradores; quem chama verifica o papel
+pub fn handle_admin_command(
+    context: &BotContext,
+    command: AdminCommand,
+) -> Result<Reply, AppError> {
+    let storage = context.storage.as_ref();
+    let text = match command {
+        AdminCommand::AddUser(ref args) | AdminCommand::AddAdmin(ref args) => {
+            let admin = matches!(command, AdminCommand::AddAdmin(_));
+            let (chat_id, name) = match args.trim().split_once(' ') {
+                Some((chat_id, name)) => (chat_id, Some(name.trim())),
+                None => (args.trim(), None),
+            };
+            let Ok(chat_id) = chat_id.parse::<i64>() else {
+                return Ok(Reply::Text(tr!("bot.usage_adduser")));
+            };
+            let user = users::add_user(storage, chat_id, name, admin)?;
+            tr!(
+                "bot_users.added",
+                name = user.name,
+                chat_id = user.chat_id,
+                admin = if user.admin { "yes" } else { "" },
+                list = user.wishlist
+            )
+        }
+        AdminCommand::RemoveUser(args) => {
+            let Ok(chat_id) = args.trim().parse::<i64>() else {
+                return Ok(Reply::Text(tr!("bot.usage_removeuser")));
+            };
+            match users::remove_user(storage, chat_id)? {
+                Some(user) => tr!(
+                    "bot_users.removed",
+                    name = user.name,
+                    chat_id = user.chat_id,
+                    list = user.wishlist
+                ),
+                None => tr!("bot_users.not_allowed", chat_id = chat_id),
+            }
+        }
+        AdminCommand::Users => {
+            let mut lines = Vec::new();
+            if let Some(owner) = context.owner_chat_id {
+                lines.push(tr!("bot.users_owner", chat_id = owner, list = context.list));
+            }
+            for user in storage.bot_users()? {
+                lines.push(tr!(
+                    "bot.users_line",
+                    name = user.name,
+                    chat_id = user.chat_id,
+                    admin = if user.admin { "yes" } else { "" },
+                    list = user.wishlist
+                ));
+            }
+            if lines.is_empty() {
+                tr!("bot.no_users")
+            } else {
+                lines.join("\n")
+            }
+        }
+    };
+    Ok(Reply::Text(text))
+}
+
+// Trata o botão escolhido numa resposta com opções
+pub async fn handle_choice(
+    context: &BotContext,
+    user: &BotUser,
+    data: &str,
+) -> Result<String, AppError> {
+    let list = user.wishlist.as_str();
+    let mut parts = data.strip_prefix("add:").unwrap_or_default().split(':');
+    let blueprint = parts
+        .next()
+        .and_then(|id| id.parse().ok())
+        .and_then(|id| context.cache.get_blueprint(id));
+    let Some(blueprint) = blueprint else {
+        return Ok(tr!("bot.choice_expired"));
+    };
+    let target_price = parts.next().and_then(parse_price);
+    add_blueprint(context, list, &blueprint, target_price).await
+}
+
+async fn add(context: &BotContext, list: &str, text: &str) -> Result<Reply, AppError> {
+    let Some(mut args) = parse_add(text) else {
+        return Ok(Reply::Text(tr!("bot.usage_add")));
+    };
+    let mut candidates =
+        context
+            .cache
+            .find_versions(context.game_id, &args.card, args.expansion.as_deref(), None);
+    if candidates.is_empty() && args.target_price.is_some() {
+        // "Agent 007": o número final pode ser parte do nome e não o preço
+        if let Some(full) = parse_card(text) {
+            let versions = context.cache.find_versions(
+                context.game_id,
+                &full.card,
+                full.expansion.as_deref(),
+                None,
+            );
+            if !versions.is_empty() {
+                args = full;
+                candidates = versions;
+            }
+        }
+    }
+    let expansion = args.expansion.as_deref();
+    let exact = !candidates.is_empty();
+    if !exact {
+        // Sem o nome exato, oferece as versões dos nomes mais parecidos
+        candidates = context
+            .cache
+            .search(context.game_id, &args.card, 3)
+            .into_iter()
+            .flat_map(|hit| {
+                context
+                    .cache
+                    .find_versions(context.game_id, &hit.name, expansion, None)
+            })
+            .collect();
+    }
+
+    match candidates.as_slice() {
+        [] => Ok(Reply::Text(tr!("add.not_found", card = args.card))),
+        [blueprint] if exact => add_blueprint(context, list, blueprint, args.target_price)
+            .await
+            .map(Reply::Text),
+        _ => {
+            let mut text = if exact {
+                tr!("bot.versions", card = args.card, count = candidates.len())
+            } else {
+                tr!("bot.did_you_mean", card = args.card)
+            };
+            if candidates.len() > MAX_CHOICES {
+                text.push('\n');
+                text.push_str(&tr!("bot.too_many", count = MAX_CHOICES));
+            }
+            let buttons = candidates
+                .iter()
+                .take(MAX_CHOICES)
+                .map(|bp| {
+                    (
+                        version_label(bp),
+                        add_callback(bp.blueprint_id, args.target_price),
+                    )
+                })
+                .collect();
+            Ok(Reply::Choices(text, buttons))
+        }
+    }
+}
+
+async fn add_blueprint(
+    context: &BotContext,
+    list: &str,
+    blueprint: &BlueprintData,
+    target_price: Option<f64>,
+) -> Result<String, AppError> {
+    let item = match target_price {
+        Some(target_price) => WishlistItem::from_blueprint(blueprint, target_price),
+        None => match wishlist::quote_item(context.source.as_ref(), blueprint, 1, None).await? {
+            Some(item) => item,
+            None => return Ok(tr!("bot.no_listings", card = version_label(blueprint))),
+        },
+    };
+    if !wishlist::add_to_wishlist(context.storage.as_ref(), list, item.clone())? {
+        return Ok(tr!("bot.duplicate", item = describe_item(&item)));
+    }
+    Ok(tr!("item.added", item = describe_item(&item)))
+}
+
+fn show(context: &BotContext, list: &str) -> Result<String, AppError> {
+    let items = wishlist::load_wishlist(context.storage.as_ref(), list)?;
+    if items.is_empty() {
+        return Ok(tr!("bot.empty"));
+    }
+    Ok(items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| format!("{}. {}", index + 1, describe_item(item)))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+fn remove(context: &BotContext, list: &str, args: &str) -> Result<String, AppError> {
+    let storage = context.storage.as_ref();
+    let items = wishlist::load_wishlist(storage, list)?;
+    let Some(filter) = select_items(&items, args) else {
+        return Ok(tr!("bot.usage_remove"));
+    };
+    let removed = wishlist::remove_items(storage, list, &filter)?;
+    if removed.is_empty() {
+        return Ok(tr!("item.no_match", card = args.trim()));
+    }
+    let lines: Vec<String> = removed
+        .iter()
+        .map(|item| tr!("item.removed", item = describe_item(item)))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn target(context: &BotContext, list: &str, args: &str) -> Result<String, AppError> {
+    let Some((reference, price)) = args.trim().rsplit_once(' ') else {
+        return Ok(tr!("bot.usage_target"));
+    };
+    let Some(target_price) = parse_price(price) else {
+        return Ok(tr!("bot.usage_target"));
+    };
+    let storage = context.storage.as_ref();
+    let items = wishlist::load_wishlist(storage, list)?;
+    let Some(filter) = select_items(&items, reference) else {
+        return Ok(tr!("bot.usage_target"));
+    };
+    match items.iter().filter(|item| filter.matches(item)).count() {
+        0 => return Ok(tr!("item.no_match", card = reference.trim())),
+        1 => {}
+        count => {
+            return Ok(tr!(
+                "bot.target_ambiguous",
+                card = reference.trim(),
+                count = count
+            ))
+        }
+    }
+    let changes = ItemChanges {
+        target_price: Some(target_price),
+        quantity: None,
+    };
+    let edited = wishlist::edit_items(storage, list, &filter, &changes)?;
+    let lines: Vec<String> = edited
+        .iter()
+        .map(|item| tr!("item.updated", item = describe_item(item)))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+// Canais são dados separados por espaço e substituem os da wishlist;
+// "default" volta para os canais padrão
+fn alerts(context: &BotContext, user: &BotUser, args: &str) -> Result<String, AppError> {
+    let mut info = wishlist::get_wishlist(context.storage.as_ref(), &user.wishlist)?;
+    match args.trim().to_lowercase().as_str() {
+        "" => {}
+        "on" => info.alerts.enabled = true,
+        "off" => info.alerts.enabled = false,
+        "default" => info.alerts.channels.clear(),
+        _ => {
+            let channels: Result<Vec<Channel>, String> =
+                args.split_whitespace().map(str::parse).collect();
+            let channels = match channels {
+                Ok(channels) => channels,
+                Err(e) => return Ok(format!("{}\n{}", e, tr!("bot.usage_alerts"))),
+            };
+            // Quem não é admin só recebe no próprio chat: os outros canais
+            // fariam o servidor chamar URLs ou mandar emails escolhidos por
+            // qualquer pessoa autorizada
+            let own_chat = |channel: &Channel| match channel {
+                Channel::Telegram { chat_id } => chat_id.is_none_or(|id| id == user.chat_id),
+                _ => false,
+            };
+            if !user.admin && !channels.iter().all(own_chat) {
+                return Ok(tr!("bot.alerts_admin_only"));
+            }
+            info.alerts.channels = channels;
+        }
+    }
+    context.storage.save_wishlist_info(&info)?;
+
+    let channels: Vec<String> = info
+        .alerts
+        .channels
+        .iter()
+        .map(Channel::to_string)
+        .collect();
+    Ok(tr!(
+        "bot.alerts",
+        list = info.name,
+        on = if info.alerts.enabled { "yes" } else { "" },
+        channels = channels.join(", ")
+    ))
+}
+
+async fn check(context: &BotContext, list: &str) -> Result<String, AppError> {
+    let report = wishlist::check_wishlist_prices(
+        context.storage.as_ref(),
+        Some(list),
+        Arc::clone(&context.source),
+    )
+    .await?;
+    Ok(tr!(
+        "check.summary",
+        checked = report.checked,
+        alerts = report.alerts.len(),
+        suppressed = report.suppressed,
+        no_listings = report.no_listings,
+        failed = report.failures.len()
+    ))
+}
+
+fn history(context: &BotContext, list: &str, args: &str) -> Result<String, AppError> {
+    let storage = context.storage.as_ref();
+    let items = wishlist::load_wishlist(storage, list)?;
+    let Some(filter) = select_items(&items, args) else {
+        return Ok(tr!("bot.usage_history"));
+    };
+    let mut lines = Vec::new();
+    for item in items.iter().filter(|item| filter.matches(item)) {
+        lines.push(describe_item(item));
+        let observations = storage.observations(&item.key(), None)?;
+        match PriceStats::from_observations(&observations) {
+            Some(stats) => lines.push(tr!(
+                "history.stats",
+                count = stats.count,
+                min = format_amount(stats.min, &stats.currency),
+                max = format_amount(stats.max, &stats.currency),
+                average = format_amount(stats.average, &stats.currency),
+                change = stats
+                    .percent_change()
+                    .map(|change| format!("{:+.1}%", change))
+                    .unwrap_or_else(|| "-".to_string())
+            )),
+            None => lines.push(tr!("history.empty")),
+        }
+        // Só as observações mais recentes cabem numa mensagem
+        let skip = observations.len().saturating_sub(HISTORY_LINES);
+        for observation in &observations[skip..] {
+            lines.push(format!(
+                "{}  {:.2} {}",
+                observation.timestamp.format("%Y-%m-%d %H:%M"),
+                observation.price,
+                observation.currency
+            ));
+        }
+    }
+    if lines.is_empty() {
+        return Ok(tr!("item.no_match", card = args.trim()));
+    }
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{self, Locale};
+    use crate::pricing::{FixturePriceSource, PriceQuote};
+    use crate::storage::SqliteStorage;
+    use crate::wishlist::DEFAULT_WISHLIST;
+    use std::collections::HashMap;
+
+    fn blueprint(blueprint_id: u32, expansion_name: &str) -> BlueprintData {
+        BlueprintData {
+            blueprint_id,
+            card_name: "Sol Ring".to_string(),
+            collector_number: blueprint_id.to_string(),
+            expansion_name: expansion_name.to_string(),
+            ..BlueprintData::default()
+        }
+    }
+
+    fn context() -> BotContext {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        let cache = BlueprintCache::new();
+        cache.load_blueprints(vec![blueprint(11, "Alpha"), blueprint(12, "Beta")]);
+        let quote = PriceQuote::new(3.0, "EUR");
+        let quotes = HashMap::from([("11".to_string(), Some(quote))]);
+        BotContext {
+            storage: Arc::new(storage),
+            cache: Arc::new(cache),
+            source: Arc::new(FixturePriceSource::new(quotes)),
+            list: DEFAULT_WISHLIST.to_string(),
+            game_id: None,
+            owner_chat_id: Some(1),
+        }
+    }
+
+    fn owner() -> BotUser {
+        BotUser {
+            chat_id: 1,
+            name: "owner".to_string(),
+            admin: true,
+            wishlist: DEFAULT_WISHLIST.to_string(),
+        }
+    }
+
+    #[test]
+    fn add_arguments_take_the_set_and_target_price() {
+        assert_eq!(
+            parse_add("Lightning Bolt (M11) 1,50"),
+            Some(AddArgs {
+                card: "Lightning Bolt".to_string(),
+                expansion: Some("M11".to_string()),
+                target_price: Some(1.5),
+            })
+        );
+        assert_eq!(
+            parse_add(" Sol Ring "),
+            Some(AddArgs {
+                card: "Sol Ring".to_string(),
+                expansion: None,
+                target_price: None,
+            })
+        );
+        assert_eq!(parse_add("(M11) 2"), None);
+        assert_eq!(parse_add(""), None);
+    }
+
+    #[tokio::test]
+    async fn card_names_may_end_in_a_number() {
+        let context = context();
+        context.cache.load_blueprints(vec![BlueprintData {
+            card_name: "Agent 007".to_string(),
+            ..blueprint(13, "Gamma")
+        }]);
+
+        let reply = handle_command(&context, &owner(), Command::Add("Agent 007 2".to_string()))
+            .await
+            .unwrap();
+        assert!(matches!(reply, Reply::Text(_)));
+        // Sem preço desejado, a cotação da versão 13 é buscada
+        let reply = handle_command(&context, &owner(), Command::Add("Agent 007".to_string())).await;
+        assert!(matches!(reply, Err(AppError::CardNotFound(e)) if e.contains("13")));
+        let items = wishlist::load_wishlist(context.storage.as_ref(), DEFAULT_WISHLIST).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].card_name, "Agent 007");
+        assert_eq!(items[0].target_price, 2.0);
+    }
+
+    #[tokio::test]
+    async fn commands_manage_the_wishlist() {
+        let context = context();
+
+        let reply = handle_command(&context, &owner(), Command::Add("Sol Ring 2".to_string()))
+            .await
+            .unwrap();
+        let Reply::Choices(_, buttons) = reply else {
+            panic!("expected a version choice, got {:?}", reply);
+        };
+        assert_eq!(buttons.len(), 2);
+        assert_eq!(buttons[0].1, "add:11:2");
+        let added = handle_choice(&context, &owner(), &buttons[1].1)
+            .await
+            .unwrap();
+        assert!(added.starts_with("Added Sol Ring (12) - Beta"), "{}", added);
+
+        // Sem preço, o preço atual vira o desejado
+        let reply = handle_command(
+            &context,
+            &owner(),
+            Command::Add("Sol Ring (Alpha)".to_string()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            reply,
+            Reply::Text("Added Sol Ring (11) - Alpha: target 3.00, last seen 3.00".to_string())
+        );
+
+        let reply = handle_command(
+            &context,
+            &owner(),
+            Command::Target("Sol Ring 1".to_string()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            reply,
+            Reply::Text("Sol Ring matches 2 cards, use its number from /list".to_string())
+        );
+        handle_command(&context, &owner(), Command::Target("2 1.5".to_string()))
+            .await
+            .unwrap();
+        handle_command(&context, &owner(), Command::Remove("1".to_string()))
+            .await
+            .unwrap();
+
+        let items = context.storage.load_wishlist(DEFAULT_WISHLIST).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].blueprint_id, Some(11));
+        assert_eq!(items[0].target_price, 1.5);
+    }
+
+    #[test]
+    fn alerts_choose_the_channels() {
+        let context = context();
+        let user = users::add_user(context.storage.as_ref(), 7, None, false).unwrap();
+
+        let reply = alerts(&context, &owner(), "telegram ntfy:cards").unwrap();
+        assert_eq!(
+            reply,
+            "Alerts of the default wishlist are on, sent to telegram, ntfy:https://ntfy.sh/cards"
+        );
+        let reply = alerts(&context, &user, "telegram:7").unwrap();
+        assert_eq!(
+            reply,
+            "Alerts of the chat7 wishlist are on, sent to telegram:7"
+        );
+        // Outros chats, URLs e emails são só para admins
+        for spec in [
+            "telegram:1",
+            "telegram webhook:http://169.254.169.254/latest",
+            "email:someone@example.com",
+        ] {
+            let reply = alerts(&context, &user, spec).unwrap();
+            assert!(
+                reply.starts_with("Only admins can send alerts"),
+                "{}",
+                reply
+            );
+        }
+        let info = wishlist::get_wishlist(context.storage.as_ref(), "chat7").unwrap();
+        assert_eq!(info.alerts.channels.len(), 1);
+        assert!(alerts(&context, &user, "pager:1")
+            .unwrap()
+            .starts_with("unknown channel"));
+
+        alerts(&context, &user, "default").unwrap();
+        let info = wishlist::get_wishlist(context.storage.as_ref(), "chat7").unwrap();
+        assert!(info.alerts.channels.is_empty());
+
+        let reply = i18n::with_locale(Locale::PtBr, || alerts(&context, &user, "off")).unwrap();
+        assert_eq!(reply, "Os alertas da wishlist chat7 estão desligados");
+    }
+}
@@ -0,0 +1,384 @@
+mod commands;
+mod users;
+
+pub use commands::{
+    bot_commands, handle_admin_command, handle_choice, handle_command, AdminCommand, BotContext,
+    Command, Reply,
+};
+pub use users::{add_user, authorize, remove_user, BotUser};
+
+use crate::error::AppError;
+use crate::notify::{split_message, telegram};
+use crate::tr;
+use dotenv::dotenv;
+use std::env;
+use std::sync::Arc;
+use teloxide::dispatching::{DefaultKey, DispatcherBuilder};
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use teloxide::RequestError;
+
+// TELEGRAM_CHAT_ID é o dono do bot, sempre autorizado e administrador
+pub fn owner_chat_id() -> Result<Option<i64>, AppError> {
+    dotenv().ok();
+    match env::var("TELEGRAM_CHAT_ID") {
+        Ok(chat_id) => chat_id
+            .parse()
+            .map(Some)
+            .map_err(|_| AppError::Config("TELEGRAM_CHAT_ID must be a valid i64".to_string())),
+        Err(_) => Ok(None),
+    }
+}
+
+// Atende os comandos até o processo terminar. Com TELEGRAM_API_URL o bot
+// conversa com outro servidor da Bot API (por exemplo um falso, local).
+pub async fn run(context: BotContext) -> Result<(), AppError> {
+    dotenv().ok();
+    let token =
+        env::var("TELEGRAM_TOKEN").map_err(|e| AppError::missing_env("TELEGRAM_TOKEN", e))?;
+    let bot = telegram::bot(&token)?;
+    bot.set_my_commands(bot_commands()).await?;
+
+    dispatcher(bot, context)
+        .enable_ctrlc_handler()
+        .build()
+        .dispatch()
+        .await;
+    Ok(())
+}
+
+// Comandos, comandos de admin e botões de escolha
+fn dispatcher(bot: Bot, context: BotContext) -> DispatcherBuilder<Bot, RequestError, DefaultKey> {
+    let handler = dptree::entry()
+        .branch(
+            Update::filter_message()
+                .branch(
+                    dptree::entry()
+                        .filter_command::<Command>()
+                        .endpoint(on_command),
+                )
+                .branch(
+                    dptree::entry()
+                        .filter_command::<AdminCommand>()
+                        .endpoint(on_admin_command),
+                ),
+        )
+        .branch(Update::filter_callback_query().endpoint(on_choice));
+    Dispatcher::builder(bot, handler).dependencies(dptree::deps![Arc::new(context)])
+}
+
+// Quem não está autorizado recebe o id do chat para pedir acesso a um admin
+async fn allowed_user(
+    bot: &Bot,
+    context: &BotContext,
+    chat_id: ChatId,
+) -> ResponseResult<Option<BotUser>> {
+    match authorize(
+        context.storage.as_ref(),
+        context.owner_chat_id,
+        &context.list,
+        chat_id.0,
+    ) {
+        Ok(Some(user)) => Ok(Some(user)),
+        Ok(None) => {
+            let text = tr!("bot.not_allowed", chat_id = chat_id);
+            bot.send_message(chat_id, text).await?;
+            Ok(None)
+        }
+        Err(e) => {
+            bot.send_message(chat_id, tr!("error", error = e)).await?;
+            Ok(None)
+        }
+    }
+}
+
+async fn send_reply(bot: &Bot, chat_id: ChatId, reply: Reply) -> ResponseResult<()> {
+    match reply {
+        Reply::Text(text) => {
+            for chunk in split_message(&text, 4000) {
+                bot.send_message(chat_id, chunk).await?;
+            }
+        }
+        Reply::Choices(text, buttons) => {
+            let keyboard = InlineKeyboardMarkup::new(
+                buttons
+                    .into_iter()
+                    .map(|(label, data)| vec![InlineKeyboardButton::callback(label, data)]),
+            );
+            bot.send_message(chat_id, text)
+                .reply_markup(keyboard)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn on_command(
+    bot: Bot,
+    message: Message,
+    command: Command,
+    context: Arc<BotContext>,
+) -> ResponseResult<()> {
+    let Some(user) = allowed_user(&bot, &context, message.chat.id).await? else {
+        return Ok(());
+    };
+    let reply = handle_command(&context, &user, command)
+        .await
+        .unwrap_or_else(|e| Reply::Text(tr!("error", error = e)));
+    send_reply(&bot, message.chat.id, reply).await
+}
+
+async fn on_admin_command(
+    bot: Bot,
+    message: Message,
+    command: AdminCommand,
+    context: Arc<BotContext>,
+) -> ResponseResult<()> {
+    let Some(user) = allowed_user(&bot, &context, message.chat.id).await? else {
+        return Ok(());
+    };
+    let reply = if user.admin {
+        handle_admin_command(&context, command)
+            .unwrap_or_else(|e| Reply::Text(tr!("error", error = e)))
+    } else {
+        Reply::Text(tr!("bot.admins_only"))
+    };
+    send_reply(&bot, message.chat.id, reply).await
+}
+
+async fn on_choice(bot: Bot, query: CallbackQuery, context: Arc<BotContext>) -> ResponseResult<()> {
+    let (Some(data), Some(message)) = (query.data, query.message) else {
+        bot.answer_callback_query(query.id).await?;
+        return Ok(());
+    };
+    let user = authorize(
+        context.storage.as_ref(),
+        context.owner_chat_id,
+        &context.list,
+        message.chat.id.0,
+    );
+    let Ok(Some(user)) = user else {
+        bot.answer_callback_query(query.id)
+            .text(tr!("bot.not_allowed_short"))
+            .await?;
+        return Ok(());
+    };
+    bot.answer_callback_query(query.id).await?;
+    let text = handle_choice(&context, &user, &data)
+        .await
+        .unwrap_or_else(|e| tr!("error", error = e));
+    // A resposta substitui os botões, assim a escolha não é feita duas vezes
+    bot.edit_message_text(message.chat.id, message.id, text)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::blueprint::BlueprintData;
+    use crate::cache::BlueprintCache;
+    use crate::pricing::{FixturePriceSource, PriceQuote};
+    use crate::storage::SqliteStorage;
+    use crate::wishlist::DEFAULT_WISHLIST;
+    use serde_json::{json, Value};
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::sync::mpsc;
+
+    // Servidor falso da Bot API: entrega as atualizações da fila, responde
+    // com um botão apertado à primeira mensagem com botões e repassa as
+    // chamadas para o teste
+    #[derive(Default)]
+    struct FakeTelegram {
+        updates: Mutex<VecDeque<Value>>,
+        next_id: Mutex<i64>,
+    }
+
+    fn chat() -> Value {
+        json!({"id": 1, "type": "private", "first_name": "Owner"})
+    }
+
+    fn user() -> Value {
+        json!({"id": 1, "is_bot": false, "first_name": "Owner"})
+    }
+
+    impl FakeTelegram {
+        fn push(&self, update: Value) {
+            let mut next_id = self.next_id.lock().unwrap();
+            *next_id += 1;
+            let mut update = update;
+            update["update_id"] = json!(*next_id);
+            self.updates.lock().unwrap().push_back(update);
+        }
+
+        fn answer(&self, method: &str, body: &Value) -> Value {
+            let message =
+                |text: &Value| json!({"message_id": 2, "date": 0, "chat": chat(), "text": text});
+            match method {
+                "getme" => json!({
+                    "id": 99, "is_bot": true, "first_name": "Cards", "username": "cards_bot",
+                    "can_join_groups": true, "can_read_all_group_messages": false,
+                    "supports_inline_queries": false
+                }),
+                "getupdates" => json!(self.updates.lock().unwrap().drain(..).collect::<Vec<_>>()),
+                "sendmessage" => {
+                    let sent = message(&body["text"]);
+                    // Aperta o segundo botão, como a pessoa faria
+                    if let Some(data) =
+                        body["reply_markup"]["inline_keyboard"][1][0]["callback_data"].as_str()
+                    {
+                        self.push(json!({"callback_query": {
+                            "id": "7", "from": user(), "message": sent,
+                            "chat_instance": "1", "data": data
+                        }}));
+                    }
+                    sent
+                }
+                "editmessagetext" => message(&body["text"]),
+                _ => json!(true),
+            }
+        }
+    }
+
+    async fn serve(
+        stream: TcpStream,
+        telegram: Arc<FakeTelegram>,
+        calls: mpsc::UnboundedSender<(String, Value)>,
+    ) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).await? == 0 {
+                return Ok(());
+            }
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).await?;
+                let header = header.trim().to_lowercase();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some(value) = header.strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap_or(0);
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).await?;
+            let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+            let method = path.rsplit('/').next().unwrap_or_default().to_lowercase();
+            let result = telegram.answer(&method, &body);
+            if method == "getupdates" && result.as_array().is_some_and(Vec::is_empty) {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            let _ = calls.send((method, body));
+
+            let response = json!({"ok": true, "result": result}).to_string();
+            writer
+                .write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                        response.len(),
+                        response
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+        }
+    }
+
+    fn context() -> BotContext {
+        let blueprint = |blueprint_id: u32, expansion_name: &str| BlueprintData {
+            blueprint_id,
+            card_name: "Sol Ring".to_string(),
+            collector_number: blueprint_id.to_string(),
+            expansion_name: expansion_name.to_string(),
+            ..BlueprintData::default()
+        };
+        let cache = BlueprintCache::new();
+        cache.load_blueprints(vec![blueprint(11, "Alpha"), blueprint(12, "Beta")]);
+        let quotes = HashMap::from([("12".to_string(), Some(PriceQuote::new(3.0, "EUR")))]);
+        BotContext {
+            storage: Arc::new(SqliteStorage::open_in_memory().unwrap()),
+            cache: Arc::new(cache),
+            source: Arc::new(FixturePriceSource::new(quotes)),
+            list: DEFAULT_WISHLIST.to_string(),
+            game_id: None,
+            owner_chat_id: Some(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_with_a_version_choice_goes_through_the_bot_api() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let telegram = Arc::new(FakeTelegram::default());
+        let (calls_tx, mut calls) = mpsc::unbounded_channel();
+        let server_telegram = Arc::clone(&telegram);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(
+                    stream,
+                    Arc::clone(&server_telegram),
+                    calls_tx.clone(),
+                ));
+            }
+        });
+        telegram.push(json!({"message": {
+            "message_id": 1, "date": 0, "chat": chat(), "from": user(),
+            "text": "/add Sol Ring 2",
+            "entities": [{"type": "bot_command", "offset": 0, "length": 4}]
+        }}));
+
+        let context = context();
+        let storage = Arc::clone(&context.storage);
+        let bot = Bot::new("123:abc").set_api_url(url.parse().unwrap());
+        let mut dispatcher = dispatcher(bot, context).build();
+        let dispatching = tokio::spawn(async move { dispatcher.dispatch().await });
+
+        let mut seen = Vec::new();
+        let edited = tokio::time::timeout(Duration::from_secs(10), async {
+            while let Some((method, body)) = calls.recv().await {
+                if method == "editmessagetext" {
+                    return body;
+                }
+                if method != "getupdates" {
+                    seen.push((method, body));
+                }
+            }
+            panic!("the fake Bot API stopped");
+        })
+        .await
+        .expect("the bot never answered the choice");
+        dispatching.abort();
+
+        let choices = seen
+            .iter()
+            .find(|(method, _)| method == "sendmessage")
+            .map(|(_, body)| body)
+            .unwrap();
+        assert_eq!(choices["text"], "Sol Ring has 2 versions, pick one:");
+        assert!(seen
+            .iter()
+            .any(|(method, _)| method == "answercallbackquery"));
+        assert!(
+            edited["text"]
+                .as_str()
+                .unwrap()
+                .starts_with("Added Sol Ring (12) - Beta"),
+            "{}",
+            edited
+        );
+        let items = storage.load_wishlist(DEFAULT_WISHLIST).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].blueprint_id, Some(12));
+        assert_eq!(items[0].target_price, 2.0);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error::AppError;
+use crate::storage::Storage;
+use crate::wishlist::{self, WishlistInfo};
+use serde::{Deserialize, Serialize};
+
+// Um chat autorizado a usar o bot. Cada chat tem a sua wishlist, cujos
+// alertas vão para ele.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BotUser {
+    pub chat_id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub admin: bool,
+    pub wishlist: String,
+}
+
+// Nome da wishlist criada para um chat ("chat-1001234" num grupo)
+pub fn wishlist_for_chat(chat_id: i64) -> String {
+    format!("chat{}", chat_id)
+}
+
+// Autoriza um chat, ou muda o papel de um já autorizado. A wishlist do chat é
+// criada com os alertas indo para ele; se já existir, fica como está.
+pub fn add_user(
+    storage: &dyn Storage,
+    chat_id: i64,
+    name: Option<&str>,
+    admin: bool,
+) -> Result<BotUser, AppError> {
+    let existing = storage
+        .bot_users()?
+        .into_iter()
+        .find(|user| user.chat_id == chat_id);
+    let user = match existing {
+        Some(user) => BotUser {
+            name: name.map(str::to_string).unwrap_or(user.name),
+            admin,
+            ..user
+        },
+        None => BotUser {
+            chat_id,
+            name: name
+                .map(str::to_string)
+                .unwrap_or_else(|| chat_id.to_string()),
+            admin,
+            wishlist: wishlist_for_chat(chat_id),
+        },
+    };
+    if wishlist::get_wishlist(storage, &user.wishlist).is_err() {
+        let mut info = WishlistInfo::new(&user.wishlist);
+        info.alerts.telegram_chat_id = Some(chat_id);
+        wishlist::create_wishlist(storage, &info)?;
+    }
+    storage.save_bot_user(&user)?;
+    Ok(user)
+}
+
+// A wishlist continua existindo depois que o chat perde o acesso
+pub fn remove_user(storage: &dyn Storage, chat_id: i64) -> Result<Option<BotUser>, AppError> {
+    let user = storage
+        .bot_users()?
+        .into_iter()
+        .find(|user| user.chat_id == chat_id);
+    if user.is_some() {
+        storage.delete_bot_user(chat_id)?;
+    }
+    Ok(user)
+}
+
+// O dono (TELEGRAM_CHAT_ID) é sempre administrador e usa a wishlist com que o
+// bot foi iniciado; os outros chats precisam ter sido autorizados
+pub fn authorize(
+    storage: &dyn Storage,
+    owner_chat_id: Option<i64>,
+    owner_wishlist: &str,
+    chat_id: i64,
+) -> Result<Option<BotUser>, AppError> {
+    if owner_chat_id == Some(chat_id) {
+        return Ok(Some(BotUser {
+            chat_id,
+            name: "owner".to_string(),
+            admin: true,
+            wishlist: owner_wishlist.to_string(),
+        }));
+    }
+    Ok(storage
+        .bot_users()?
+        .into_iter()
+        .find(|user| user.chat_id == chat_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+    use crate::wishlist::DEFAULT_WISHLIST;
+
+    #[test]
+    fn only_allowed_chats_get_their_own_wishlist() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        assert_eq!(
+            authorize(&storage, Some(1), DEFAULT_WISHLIST, 7).unwrap(),
+            None
+        );
+
+        let owner = authorize(&storage, Some(1), DEFAULT_WISHLIST, 1)
+            .unwrap()
+            .unwrap();
+        assert!(owner.admin);
+        assert_eq!(owner.wishlist, DEFAULT_WISHLIST);
+
+        add_user(&storage, -1007, Some("team"), false).unwrap();
+        let user = authorize(&storage, Some(1), DEFAULT_WISHLIST, -1007)
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.wishlist, "chat-1007");
+        assert!(!user.admin);
+        let info = wishlist::get_wishlist(&storage, "chat-1007").unwrap();
+        assert_eq!(info.alerts.telegram_chat_id, Some(-1007));
+
+        // Promover mantém o nome e a wishlist
+        let promoted = add_user(&storage, -1007, None, true).unwrap();
+        assert_eq!(promoted.name, "team");
+        assert!(promoted.admin);
+
+        assert_eq!(remove_user(&storage, -1007).unwrap(), Some(promoted));
+        assert_eq!(
+            authorize(&storage, Some(1), DEFAULT_WISHLIST, -1007).unwrap(),
+            None
+        );
+        assert!(wishlist::get_wishlist(&storage, "chat-1007").is_ok());
+    }
+}
@@ -0,0 +1,386 @@
+pub mod search;
+pub mod sync;
+
+use crate::api::blueprint::BlueprintData;
+use crate::api::expansion::Expansion;
+use crate::error::AppError;
+use crate::storage::Storage;
+use search::{SearchHit, SearchIndex};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+// Os blueprints ficam num vetor e os índices guardam posições nele.
+// Os nomes são agrupados por jogo para que nomes iguais em jogos diferentes
+// não se misturem; blueprints sem jogo conhecido ficam na chave None.
+#[derive(Default)]
+struct Indexes {
+    blueprints: Vec<BlueprintData>,
+    expansions: HashMap<u32, Expansion>,
+    by_id: HashMap<u32, usize>,
+    by_game: HashMap<Option<u32>, HashMap<String, Vec<usize>>>,
+    by_expansion: HashMap<u32, Vec<usize>>,
+    // (expansão, número de coleção em minúsculas)
+    by_number: HashMap<(u32, String), Vec<usize>>,
+    // Código em minúsculas -> ids das expansões (o mesmo código pode existir
+    // em jogos diferentes)
+    by_code: HashMap<String, Vec<u32>>,
+    search: HashMap<Option<u32>, SearchIndex>,
+}
+
+impl Indexes {
+    fn rebuild(&mut self) {
+        self.by_id.clear();
+        self.by_game.clear();
+        self.by_expansion.clear();
+        self.by_number.clear();
+        for (position, blueprint) in self.blueprints.iter().enumerate() {
+            self.by_id.insert(blueprint.blueprint_id, position);
+            self.by_game
+                .entry(blueprint.game_id)
+                .or_default()
+                .entry(blueprint.card_name.clone())
+                .or_default()
+                .push(position);
+            if let Some(expansion_id) = blueprint.expansion_id {
+                self.by_expansion
+                    .entry(expansion_id)
+                    .or_default()
+                    .push(position);
+                self.by_number
+                    .entry((expansion_id, blueprint.collector_number.to_lowercase()))
+                    .or_default()
+                    .push(position);
+            }
+        }
+
+        self.by_code.clear();
+        for expansion in self.expansions.values() {
+            if let Some(code) = &expansion.code {
+                self.by_code
+                    .entry(code.to_lowercase())
+                    .or_default()
+                    .push(expansion.id);
+            }
+        }
+
+        self.search = self
+            .by_game
+            .iter()
+            .map(|(game_id, names)| (*game_id, SearchIndex::new(names.keys().cloned())))
+            .collect();
+    }
+
+    fn collect(&self, positions: Option<&Vec<usize>>) -> Vec<BlueprintData> {
+        positions
+            .into_iter()
+            .flatten()
+            .map(|&position| self.blueprints[position].clone())
+            .collect()
+    }
+
+    fn in_game(&self, expansion_id: u32, game_id: Option<u32>) -> bool {
+        game_id.is_none()
+            || self
+                .expansions
+                .get(&expansion_id)
+                .is_some_and(|expansion| expansion.game_id == game_id)
+    }
+}
+
+pub struct BlueprintCache {
+    cache: Mutex<Indexes>,
+}
+
+impl BlueprintCache {
+    pub fn new() -> Self {
+        BlueprintCache {
+            cache: Mutex::new(Indexes::default()),
+        }
+    }
+
+    pub fn load_from_storage(&self, storage: &dyn Storage) -> Result<(), AppError> {
+        let expansions = storage.load_expansions()?;
+        // Registros antigos não têm jogo, id nem código da expansão; usamos
+        // os dados da expansão salva
+        let by_id: HashMap<u32, &Expansion> = expansions.iter().map(|e| (e.id, e)).collect();
+        let by_name: HashMap<&str, &Expansion> =
+            expansions.iter().map(|e| (e.name.as_str(), e)).collect();
+
+        let mut blueprints = storage.load_blueprints()?;
+        for blueprint in blueprints.iter_mut() {
+            let expansion = blueprint
+                .expansion_id
+                .and_then(|id| by_id.get(&id))
+                .or_else(|| by_name.get(blueprint.expansion_name.as_str()));
+            if let Some(expansion) = expansion {
+                blueprint.expansion_id = Some(expansion.id);
+                blueprint.game_id = blueprint.game_id.or(expansion.game_id);
+                if blueprint.expansion_code.is_none() {
+                    blueprint.expansion_code = expansion.code.clone();
+                }
+            }
+        }
+        self.load_expansions(expansions);
+        self.load_blueprints(blueprints);
+        Ok(())
+    }
+
+    pub fn load_expansions(&self, expansions: Vec<Expansion>) {
+        let mut cache = self.cache.lock().unwrap();
+        for expansion in expansions {
+            cache.expansions.insert(expansion.id, expansion);
+        }
+        cache.rebuild();
+    }
+
+    pub fn load_blueprints(&self, blueprints: Vec<BlueprintData>) {
+        let mut cache = self.cache.lock().unwrap();
+        cache.blueprints.extend(blueprints);
+        cache.rebuild();
+    }
+
+    pub fn get_blueprint(&self, blueprint_id: u32) -> Option<BlueprintData> {
+        let cache = self.cache.lock().unwrap();
+        cache
+            .by_id
+            .get(&blueprint_id)
+            .map(|&position| cache.blueprints[position].clone())
+    }
+
+    // Com game_id None procura em todos os jogos
+    pub fn get_blueprints_by_name(
+        &self,
+        game_id: Option<u32>,
+        name: &str,
+    ) -> Option<Vec<BlueprintData>> {
+        let cache = self.cache.lock().unwrap();
+        let blueprints: Vec<BlueprintData> = cache
+            .by_game
+            .iter()
+            .filter(|(game, _)| game_id.is_none() || **game == game_id)
+            .flat_map(|(_, names)| cache.collect(names.get(name)))
+            .collect();
+        if blueprints.is_empty() {
+            None
+        } else {
+            Some(blueprints)
+        }
+    }
+
+    pub fn get_all_card_names(&self, game_id: Option<u32>) -> Vec<String> {
+        let cache = self.cache.lock().unwrap();
+        let mut names: Vec<String> = cache
+            .by_game
+            .iter()
+            .filter(|(game, _)| game_id.is_none() || **game == game_id)
+            .flat_map(|(_, names)| names.keys().cloned())
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    // Jogos que têm blueprints no cache
+    pub fn game_ids(&self) -> Vec<u32> {
+        let cache = self.cache.lock().unwrap();
+        let mut game_ids: Vec<u32> = cache.by_game.keys().flatten().copied().collect();
+        game_ids.sort();
+        game_ids
+    }
+
+    pub fn get_expansion(&self, expansion_id: u32) -> Option<Expansion> {
+        let cache = self.cache.lock().unwrap();
+        cache.expansions.get(&expansion_id).cloned()
+    }
+
+    // Resolve um código como "MH3" (sem diferenciar maiúsculas)
+    pub fn find_expansions_by_code(&self, game_id: Option<u32>, code: &str) -> Vec<Expansion> {
+        let cache = self.cache.lock().unwrap();
+        cache
+            .by_code
+            .get(&code.to_lowercase())
+            .into_iter()
+            .flatten()
+            .filter(|&&expansion_id| cache.in_game(expansion_id, game_id))
+            .filter_map(|expansion_id| cache.expansions.get(expansion_id).cloned())
+            .collect()
+    }
+
+    // Todas as cartas de uma expansão
+    pub fn get_blueprints_by_expansion(&self, expansion_id: u32) -> Vec<BlueprintData> {
+        let cache = self.cache.lock().unwrap();
+        cache.collect(cache.by_expansion.get(&expansion_id))
+    }
+
+    pub fn get_blueprints_by_number(
+        &self,
+        expansion_id: u32,
+        collector_number: &str,
+    ) -> Vec<BlueprintData> {
+        let cache = self.cache.lock().unwrap();
+        cache.collect(
+            cache
+                .by_number
+                .get(&(expansion_id, collector_number.to_lowercase())),
+        )
+    }
+
+    // Nomes de carta ordenados pela relevância para a busca
+    pub fn search(&self, game_id: Option<u32>, query: &str, limit: usize) -> Vec<SearchHit> {
+        let cache = self.cache.lock().unwrap();
+        let mut hits: Vec<SearchHit> = cache
+            .search
+            .iter()
+            .filter(|(game, _)| game_id.is_none() || **game == game_id)
+            .flat_map(|(_, index)| index.search(query, limit))
+            .collect();
+        hits.sort_by(|a, b| {
+            (a.score, a.name.len(), &a.name).cmp(&(b.score, b.name.len(), &b.name))
+        });
+        hits.dedup_by(|a, b| a.name == b.name);
+        hits.truncate(limit);
+        hits
+    }
+
+    // Busca por código da expansão + número de coleção ("MH3 123")
+    pub fn find_by_set_and_number(
+        &self,
+        game_id: Option<u32>,
+        code: &str,
+        collector_number: &str,
+    ) -> Vec<BlueprintData> {
+        self.find_expansions_by_code(game_id, code)
+            .iter()
+            .flat_map(|expansion| self.get_blueprints_by_number(expansion.id, collector_number))
+            .collect()
+    }
+
+    // Versões de uma carta pelo nome exato; a expansão aceita o nome ou o código
+    pub fn find_versions(
+        &self,
+        game_id: Option<u32>,
+        card_name: &str,
+        expansion: Option<&str>,
+        collector_number: Option<&str>,
+    ) -> Vec<BlueprintData> {
+        let expansion_ids: Vec<u32> = expansion
+            .map(|e| {
+                self.find_expansions_by_code(game_id, e)
+                    .iter()
+                    .map(|expansion| expansion.id)
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.get_blueprints_by_name(game_id, card_name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|bp| {
+                expansion.is_none_or(|e| {
+                    bp.expansion_name.eq_ignore_ascii_case(e)
+                        || bp
+                            .expansion_id
+                            .is_some_and(|id| expansion_ids.contains(&id))
+                })
+            })
+            .filter(|bp| collector_number.is_none_or(|n| bp.collector_number == n))
+            .collect()
+    }
+}
+
+impl Default for BlueprintCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blueprint(blueprint_id: u32, card_name: &str, game_id: u32) -> BlueprintData {
+        BlueprintData {
+            blueprint_id,
+            card_name: card_name.to_string(),
+            collector_number: "1".to_string(),
+            expansion_name: "Base Set".to_string(),
+            game_id: Some(game_id),
+            ..BlueprintData::default()
+        }
+    }
+
+    #[test]
+    fn names_are_partitioned_by_game() {
+        let cache = BlueprintCache::new();
+        cache.load_blueprints(vec![
+            blueprint(1, "Pikachu", 5),
+            blueprint(2, "Lightning Bolt", 1),
+            blueprint(3, "Pikachu", 1),
+        ]);
+
+        assert_eq!(cache.get_all_card_names(Some(5)), vec!["Pikachu"]);
+        assert_eq!(cache.get_all_card_names(None).len(), 2);
+        assert_eq!(
+            cache.get_blueprints_by_name(Some(5), "Pikachu").unwrap()[0].blueprint_id,
+            1
+        );
+        assert_eq!(
+            cache.get_blueprints_by_name(None, "Pikachu").unwrap().len(),
+            2
+        );
+        assert!(cache
+            .get_blueprints_by_name(Some(5), "Lightning Bolt")
+            .is_none());
+        assert_eq!(cache.game_ids(), vec![1, 5]);
+    }
+
+    #[test]
+    fn search_stays_inside_the_game() {
+        let cache = BlueprintCache::new();
+        cache.load_blueprints(vec![
+            blueprint(1, "Pikachu", 5),
+            blueprint(2, "Lightning Bolt", 1),
+        ]);
+
+        assert_eq!(cache.search(Some(1), "pika", 5), vec![]);
+        assert_eq!(cache.search(None, "pika", 5)[0].name, "Pikachu");
+    }
+
+    #[test]
+    fn expansion_indexes_resolve_codes_and_collector_numbers() {
+        let cache = BlueprintCache::new();
+        cache.load_expansions(vec![
+            Expansion {
+                id: 10,
+                name: "Magic 2011".to_string(),
+                code: Some("M11".to_string()),
+                game_id: Some(1),
+            },
+            Expansion {
+                id: 20,
+                name: "Modern Horizons 3".to_string(),
+                code: Some("MH3".to_string()),
+                game_id: Some(1),
+            },
+        ]);
+        let mut bolt = blueprint(2, "Lightning Bolt", 1);
+        bolt.expansion_id = Some(10);
+        bolt.collector_number = "146".to_string();
+        let mut ornithopter = blueprint(3, "Ornithopter of Paradise", 1);
+        ornithopter.expansion_id = Some(20);
+        ornithopter.collector_number = "277".to_string();
+        cache.load_blueprints(vec![bolt, ornithopter]);
+
+        assert_eq!(cache.find_expansions_by_code(Some(1), "mh3")[0].id, 20);
+        assert!(cache.find_expansions_by_code(Some(5), "mh3").is_empty());
+        assert_eq!(cache.get_blueprints_by_expansion(10).len(), 1);
+        assert_eq!(cache.get_blueprints_by_number(20, "277")[0].blueprint_id, 3);
+        assert_eq!(
+            cache.find_by_set_and_number(None, "M11", "146")[0].blueprint_id,
+            2
+        );
+        assert_eq!(
+            cache.get_blueprint(3).unwrap().card_name,
+            "Ornithopter of Paradise"
+        );
+    }
+}
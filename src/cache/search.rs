@@ -0,0 +1,199 @@
+use crate::slug;
+
+// Forma usada para comparar: sem acentos, minúscula, apóstrofos removidos e o
+// resto da pontuação virando espaço ("Urza's Saga" e "urzas saga" batem).
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for c in slug::fold(text).chars() {
+        if c.is_alphanumeric() {
+            normalized.extend(c.to_lowercase());
+        } else if matches!(c, '\'' | '’' | '‘' | 'ʼ') {
+            continue;
+        } else if !normalized.is_empty() && !normalized.ends_with(' ') {
+            normalized.push(' ');
+        }
+    }
+    normalized.trim_end().to_string()
+}
+
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// Erros de digitação tolerados para o tamanho da busca
+fn max_typos(query_len: usize) -> usize {
+    match query_len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+// Quanto menor, melhor: exato, prefixo, prefixo de uma palavra, trecho e por
+// fim aproximado (levando em conta quantos erros de digitação).
+fn score(query: &str, name: &str) -> Option<usize> {
+    if name == query {
+        return Some(0);
+    }
+    if name.starts_with(query) {
+        return Some(1);
+    }
+    if name.split(' ').any(|word| word.starts_with(query)) {
+        return Some(2);
+    }
+    if name.contains(query) {
+        return Some(3);
+    }
+
+    let max_typos = max_typos(query.chars().count());
+    if max_typos == 0 {
+        return None;
+    }
+    // Compara com o nome inteiro e com o começo do nome, para quem ainda
+    // está digitando ("lightnig" deve achar "Lightning Bolt")
+    let prefix: String = name.chars().take(query.chars().count()).collect();
+    let distance = edit_distance(query, name).min(edit_distance(query, &prefix));
+    (distance <= max_typos).then_some(4 + distance)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchHit {
+    pub name: String,
+    pub score: usize,
+}
+
+// Índice de nomes de carta já normalizados
+#[derive(Default)]
+pub struct SearchIndex {
+    entries: Vec<(String, String)>,
+}
+
+impl SearchIndex {
+    pub fn new<I: IntoIterator<Item = String>>(names: I) -> Self {
+        let mut entries: Vec<(String, String)> = names
+            .into_iter()
+            .map(|name| (normalize(&name), name))
+            .collect();
+        entries.sort();
+        entries.dedup();
+        SearchIndex { entries }
+    }
+
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<(usize, &String, &String)> = self
+            .entries
+            .iter()
+            .filter_map(|(normalized, name)| {
+                score(&query, normalized).map(|score| (score, normalized, name))
+            })
+            .collect();
+        // Mesma pontuação: nomes mais curtos e depois ordem alfabética
+        hits.sort_by(|a, b| (a.0, a.1.len(), a.1).cmp(&(b.0, b.1.len(), b.1)));
+        hits.into_iter()
+            .take(limit)
+            .map(|(score, _, name)| SearchHit {
+                name: name.clone(),
+                score,
+            })
+            .collect()
+    }
+}
+
+// "MH3 123", "mh3/123" ou "MH3 #123" viram ("mh3", "123")
+pub fn parse_set_and_number(query: &str) -> Option<(String, String)> {
+    let mut parts = query
+        .split(|c: char| c.is_whitespace() || c == '/' || c == '#')
+        .filter(|part| !part.is_empty());
+    let code = parts.next()?;
+    let number = parts.next()?;
+    if parts.next().is_some()
+        || !code.chars().all(|c| c.is_ascii_alphanumeric())
+        || !number.chars().any(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((code.to_lowercase(), number.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> SearchIndex {
+        SearchIndex::new(
+            [
+                "Lightning Bolt",
+                "Lightning Helix",
+                "Chain Lightning",
+                "Lim-Dûl's Vault",
+                "Urza's Saga",
+                "Bolt",
+            ]
+            .map(String::from),
+        )
+    }
+
+    fn names(hits: Vec<SearchHit>) -> Vec<String> {
+        hits.into_iter().map(|hit| hit.name).collect()
+    }
+
+    #[test]
+    fn ranks_exact_then_prefix_then_word_prefix() {
+        assert_eq!(
+            names(index().search("lightning", 10)),
+            vec!["Lightning Bolt", "Lightning Helix", "Chain Lightning"]
+        );
+        assert_eq!(
+            names(index().search("bolt", 10)),
+            vec!["Bolt", "Lightning Bolt"]
+        );
+    }
+
+    #[test]
+    fn ignores_case_accents_and_apostrophes() {
+        assert_eq!(names(index().search("lim dul", 1)), vec!["Lim-Dûl's Vault"]);
+        assert_eq!(names(index().search("URZAS", 1)), vec!["Urza's Saga"]);
+    }
+
+    #[test]
+    fn tolerates_typos() {
+        assert_eq!(
+            names(index().search("lightnig bolt", 1)),
+            vec!["Lightning Bolt"]
+        );
+        assert_eq!(
+            names(index().search("ligthning", 1)),
+            vec!["Lightning Bolt"]
+        );
+        assert!(index().search("zzz", 10).is_empty());
+    }
+
+    #[test]
+    fn parses_set_code_and_collector_number() {
+        assert_eq!(
+            parse_set_and_number("MH3 123"),
+            Some(("mh3".to_string(), "123".to_string()))
+        );
+        assert_eq!(
+            parse_set_and_number("m11/146a"),
+            Some(("m11".to_string(), "146a".to_string()))
+        );
+        assert_eq!(parse_set_and_number("lightning bolt"), None);
+    }
+}
@@ -0,0 +1,331 @@
+use crate::api::blueprint::BlueprintData;
+use crate::api::expansion::Expansion;
+use crate::api::CardTraderClient;
+use crate::error::AppError;
+use crate::storage::Storage;
+use chrono::{DateTime, Utc};
+use indicatif::ProgressBar;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tokio::sync::Semaphore;
+use tokio::task;
+
+const MAX_CONCURRENT_REQUESTS: usize = 50; // Limite de tarefas paralelas
+
+// Quando cada expansão foi sincronizada pela última vez
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ExpansionSync {
+    pub expansion_id: u32,
+    pub synced_at: DateTime<Utc>,
+    pub blueprint_count: usize,
+}
+
+pub enum SyncMode {
+    // Só expansões que nunca foram sincronizadas
+    NewOnly,
+    All,
+    Expansions(Vec<u32>),
+}
+
+#[derive(Serialize, Default)]
+pub struct SyncSummary {
+    pub expansions: usize,
+    pub added: usize,
+    pub changed: usize,
+    pub removed: usize,
+    // Expansões que não puderam ser baixadas; as outras são gravadas
+    pub failures: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct BlueprintDiff {
+    pub added: Vec<BlueprintData>,
+    pub changed: Vec<BlueprintData>,
+    pub removed: Vec<u32>,
+}
+
+// Compara os blueprints salvos de uma expansão com os que vieram da API
+pub fn diff_blueprints(existing: &[BlueprintData], fetched: &[BlueprintData]) -> BlueprintDiff {
+    let existing_by_id: HashMap<u32, &BlueprintData> = existing
+        .iter()
+        .map(|blueprint| (blueprint.blueprint_id, blueprint))
+        .collect();
+    let fetched_ids: HashSet<u32> = fetched
+        .iter()
+        .map(|blueprint| blueprint.blueprint_id)
+        .collect();
+
+    let mut diff = BlueprintDiff::default();
+    for blueprint in fetched {
+        match existing_by_id.get(&blueprint.blueprint_id) {
+            None => diff.added.push(blueprint.clone()),
+            Some(old) if *old != blueprint => diff.changed.push(blueprint.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = existing
+        .iter()
+        .map(|blueprint| blueprint.blueprint_id)
+        .filter(|blueprint_id| !fetched_ids.contains(blueprint_id))
+        .collect();
+    diff
+}
+
+pub async fn sync_blueprints(
+    api: &CardTraderClient,
+    storage: &dyn Storage,
+    expansions: Vec<Expansion>,
+    mode: SyncMode,
+) -> Result<SyncSummary, AppError> {
+    let synced: HashSet<u32> = storage
+        .load_expansion_syncs()?
+        .iter()
+        .map(|sync| sync.expansion_id)
+        .collect();
+    storage.save_expansions(&expansions)?;
+
+    let selected: Vec<Expansion> = match &mode {
+        SyncMode::NewOnly => expansions
+            .into_iter()
+            .filter(|expansion| !synced.contains(&expansion.id))
+            .collect(),
+        SyncMode::All => expansions,
+        SyncMode::Expansions(ids) => {
+            if let Some(unknown) = ids
+                .iter()
+                .find(|id| !expansions.iter().any(|expansion| expansion.id == **id))
+            {
+                return Err(AppError::Config(format!(
+                    "unknown expansion id {}",
+                    unknown
+                )));
+            }
+            expansions
+                .into_iter()
+                .filter(|expansion| ids.contains(&expansion.id))
+                .collect()
+        }
+    };
+
+    // Blueprints salvos, agrupados por expansão. Registros antigos não têm
+    // expansion_id e são reconhecidos pelo nome da expansão.
+    let names: HashMap<String, u32> = selected
+        .iter()
+        .map(|expansion| (expansion.name.clone(), expansion.id))
+        .collect();
+    let mut existing: HashMap<u32, Vec<BlueprintData>> = HashMap::new();
+    for blueprint in storage.load_blueprints()? {
+        let expansion_id = blueprint
+            .expansion_id
+            .or_else(|| names.get(&blueprint.expansion_name).copied());
+        if let Some(expansion_id) = expansion_id {
+            existing.entry(expansion_id).or_default().push(blueprint);
+        }
+    }
+
+    let bar = ProgressBar::new(selected.len() as u64);
+    let semaphore = std::sync::Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+    let tasks: Vec<_> = selected
+        .into_iter()
+        .map(|expansion| {
+            let api = api.clone();
+            let semaphore = semaphore.clone();
+
+            task::spawn(async move {
+                let _permit = semaphore.acquire().await;
+                let blueprints = api.blueprints_export(expansion.id).await.map(|blueprints| {
+                    blueprints
+                        .into_iter()
+                        .map(|mut blueprint| {
+                            blueprint.expansion_id = Some(expansion.id);
+                            blueprint.game_id = blueprint.game_id.or(expansion.game_id);
+                            let mut data = blueprint.into_data(&expansion.name);
+                            data.expansion_code = expansion.code.clone();
+                            data
+                        })
+                        .collect::<Vec<BlueprintData>>()
+                });
+                (expansion, blueprints)
+            })
+        })
+        .collect();
+
+    let mut summary = SyncSummary::default();
+    let mut syncs = Vec::new();
+    let mut upserts = Vec::new();
+    let mut removed = Vec::new();
+    let mut first_error = None;
+    for task in tasks {
+        let (expansion, fetched) = task.await?;
+        bar.inc(1);
+        let fetched = match fetched {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                // Uma expansão com erro não impede as outras; ela não é
+                // registrada como sincronizada e volta na próxima vez
+                summary
+                    .failures
+                    .push(format!("{} ({}): {}", expansion.name, expansion.id, e));
+                first_error.get_or_insert(e);
+                continue;
+            }
+        };
+        let expansion_id = expansion.id;
+        let diff = diff_blueprints(
+            existing.get(&expansion_id).map_or(&[], Vec::as_slice),
+            &fetched,
+        );
+
+        summary.expansions += 1;
+        summary.added += diff.added.len();
+        summary.changed += diff.changed.len();
+        summary.removed += diff.removed.len();
+        syncs.push(ExpansionSync {
+            expansion_id,
+            synced_at: Utc::now(),
+            blueprint_count: fetched.len(),
+        });
+        upserts.extend(diff.added);
+        upserts.extend(diff.changed);
+        removed.extend(diff.removed);
+    }
+    bar.finish();
+
+    if let Some(e) = first_error {
+        if syncs.is_empty() {
+            return Err(e);
+        }
+    }
+    // Grava as expansões baixadas de uma vez
+    storage.apply_blueprint_sync(&syncs, &upserts, &removed)?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+    use reqwest::header::HeaderMap;
+    use reqwest::Client;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn blueprint(blueprint_id: u32, card_name: &str) -> BlueprintData {
+        BlueprintData {
+            blueprint_id,
+            card_name: card_name.to_string(),
+            version: None,
+            collector_number: blueprint_id.to_string(),
+            expansion_name: "Alpha".to_string(),
+            expansion_id: Some(1),
+            ..BlueprintData::default()
+        }
+    }
+
+    #[test]
+    fn diff_reports_added_changed_and_removed() {
+        let existing = [
+            blueprint(1, "Sol Ring"),
+            blueprint(2, "Mox Pearl"),
+            blueprint(3, "Black Lotus"),
+        ];
+        let fetched = [
+            blueprint(1, "Sol Ring"),
+            blueprint(2, "Mox Pearl (Retro Frame)"),
+            blueprint(4, "Time Walk"),
+        ];
+
+        let diff = diff_blueprints(&existing, &fetched);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].blueprint_id, 4);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].card_name, "Mox Pearl (Retro Frame)");
+        assert_eq!(diff.removed, vec![3]);
+    }
+
+    // Mock do export: a expansão 1 responde, as outras dão erro 500
+    async fn mock_export() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let (status, body) =
+                    if String::from_utf8_lossy(&request).contains("expansion_id=1 ") {
+                        (
+                            "200 OK",
+                            r#"[{"id": 10, "name": "Sol Ring", "version": null,
+                        "fixed_properties": {"collector_number": "10"}}]"#,
+                        )
+                    } else {
+                        ("500 Internal Server Error", "{}")
+                    };
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        url
+    }
+
+    fn expansion(id: u32, name: &str) -> Expansion {
+        Expansion {
+            id,
+            name: name.to_string(),
+            code: None,
+            game_id: Some(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_expansions_do_not_discard_the_others() {
+        let api = CardTraderClient::new(Client::new(), HeaderMap::new())
+            .with_base_url(&mock_export().await);
+        let storage = SqliteStorage::open_in_memory().unwrap();
+
+        let summary = sync_blueprints(
+            &api,
+            &storage,
+            vec![expansion(1, "Alpha"), expansion(2, "Beta")],
+            SyncMode::NewOnly,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.expansions, 1);
+        assert_eq!(summary.added, 1);
+        assert_eq!(summary.failures.len(), 1);
+        assert!(summary.failures[0].starts_with("Beta (2)"));
+        assert_eq!(storage.load_blueprints().unwrap().len(), 1);
+        let synced: Vec<u32> = storage
+            .load_expansion_syncs()
+            .unwrap()
+            .iter()
+            .map(|sync| sync.expansion_id)
+            .collect();
+        assert_eq!(synced, vec![1]);
+
+        // Sem nenhuma expansão baixada, é um erro
+        let result = sync_blueprints(
+            &api,
+            &storage,
+            vec![expansion(2, "Beta")],
+            SyncMode::NewOnly,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,1406 @@
+use cardtrader_client::api::game::{self, Game};
+use cardtrader_client::api::CardTraderClient;
+use cardtrader_client::bot::{self, BotContext};
+use cardtrader_client::cache::sync::{self, SyncMode, SyncSummary};
+use cardtrader_client::cache::{search, BlueprintCache};
+use cardtrader_client::decklist::{self, DeckFormat, DefaultTarget, ImportOptions, ImportReport};
+use cardtrader_client::error::AppError;
+use cardtrader_client::history::{PriceObservation, PriceStats};
+use cardtrader_client::i18n::{self, Locale};
+use cardtrader_client::notify::{Alert, AlertTemplates, Channel, Templates};
+use cardtrader_client::preferences::{Condition, FoilPreference, Preferences};
+use cardtrader_client::pricing;
+use cardtrader_client::settings::Settings;
+use cardtrader_client::slug;
+use cardtrader_client::storage::{self, JsonStorage, Storage};
+use cardtrader_client::template::Plain;
+use cardtrader_client::tr;
+use cardtrader_client::wishlist::{
+    self, describe_item, AlertSettings, ItemChanges, SortKey, WishlistFilter, WishlistInfo,
+    WishlistItem, DEFAULT_WISHLIST,
+};
+use chrono::{TimeDelta, Utc};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const EXIT_ERROR: u8 = 1;
+pub const EXIT_NOT_FOUND: u8 = 3;
+pub const EXIT_AMBIGUOUS: u8 = 4;
+// Terminou, mas alguns preços ou expansões não puderam ser consultados
+pub const EXIT_PARTIAL: u8 = 5;
+
+/// Track CardTrader prices and get alerts when cards drop below your target.
+/// Runs the interactive menu when no subcommand is given.
+#[derive(Parser)]
+#[command(name = "cardtrader_client", version)]
+pub struct Cli {
+    /// Print machine-readable JSON instead of text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Wishlist to use (checks run over every wishlist when omitted)
+    #[arg(long, global = true)]
+    pub list: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Download blueprints of expansions that were never synced
+    SyncBlueprints {
+        /// Refresh this expansion id even if it was synced before (repeatable)
+        #[arg(long = "expansion")]
+        expansions: Vec<u32>,
+        /// Refresh every expansion
+        #[arg(long, conflicts_with = "expansions")]
+        all: bool,
+    },
+    /// Add a card to the wishlist
+    Add {
+        /// Exact card name, e.g. "Lightning Bolt"
+        card: String,
+        /// Expansion name or set code (e.g. "MH3"), required when the card was printed more than once
+        #[arg(long)]
+        expansion: Option<String>,
+        /// Collector number, to pick one version inside an expansion
+        #[arg(long)]
+        collector_number: Option<String>,
+        /// Game id or name, e.g. "magic" (defaults to the game set with set-game)
+        #[arg(long)]
+        game: Option<String>,
+        /// Alert when the price drops below this value (defaults to the current price)
+        #[arg(long)]
+        target_price: Option<f64>,
+        /// Number of copies wanted
+        #[arg(long, default_value_t = 1)]
+        quantity: u32,
+        #[command(flatten)]
+        preferences: PreferenceArgs,
+    },
+    /// Import a decklist (text, MTG Arena, MTGO .dek or Moxfield/Archidekt CSV) into the wishlist
+    Import {
+        file: PathBuf,
+        /// Decklist format (detected from the file when omitted)
+        #[arg(long, value_enum)]
+        format: Option<DecklistFormat>,
+        /// Game id or name (defaults to the game set with set-game)
+        #[arg(long)]
+        game: Option<String>,
+        /// Use this target price for every card
+        #[arg(long, conflicts_with = "discount")]
+        target_price: Option<f64>,
+        /// Set the target this many percent below the current price
+        #[arg(long)]
+        discount: Option<f64>,
+        #[command(flatten)]
+        preferences: PreferenceArgs,
+    },
+    /// Show or change the default listing preferences
+    Preferences {
+        #[command(flatten)]
+        preferences: PreferenceArgs,
+        /// Accept any listing again
+        #[arg(long, conflicts_with_all = ["min_condition", "languages", "foil", "zero_only"])]
+        clear: bool,
+    },
+    /// Search card names (typos and missing accents are fine) or "SET number"
+    Search {
+        query: String,
+        /// Game id or name (defaults to the game set with set-game)
+        #[arg(long)]
+        game: Option<String>,
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// List the games available on CardTrader
+    Games,
+    /// Set the default game used when adding cards
+    SetGame {
+        /// Game id or name, e.g. "magic" or "pokemon"
+        game: String,
+    },
+    /// Check wishlist prices once and send alerts
+    Check,
+    /// Keep checking wishlist prices
+    Watch {
+        /// Seconds to wait between checks
+        #[arg(long, default_value_t = 10)]
+        interval: u64,
+    },
+    /// Show the wishlist
+    List {
+        #[arg(long, value_enum)]
+        sort: Option<ListSort>,
+    },
+    /// Change the target price or quantity of a wishlist card
+    Edit {
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[arg(long)]
+        target_price: Option<f64>,
+        #[arg(long)]
+        quantity: Option<u32>,
+    },
+    /// Remove wishlist cards matching the filter
+    Remove {
+        #[command(flatten)]
+        filter: FilterArgs,
+    },
+    /// Merge wishlist items of the same blueprint
+    Dedup,
+    /// Show the recorded price history of a wishlist card
+    History {
+        card: String,
+        #[arg(long)]
+        expansion: Option<String>,
+        /// Only consider the last N days
+        #[arg(long)]
+        days: Option<i64>,
+    },
+    /// Manage named wishlists (shows them when no action is given)
+    Wishlists {
+        #[command(subcommand)]
+        action: Option<WishlistCommand>,
+    },
+    /// Show or set the language of messages and alerts: en, pt-BR, es or it
+    Locale { locale: Option<Locale> },
+    /// Show or change the alert templates, with a preview
+    Templates {
+        /// Title of each alert message, e.g. "{{#bold}}Deals{{/bold}} ({{count}})"
+        #[arg(long)]
+        title: Option<String>,
+        /// Text of each alert, e.g. "{{card}} [{{set}}]: {{price}} ({{delta_percent}})"
+        #[arg(long)]
+        body: Option<String>,
+        /// Go back to the templates of the language
+        #[arg(long, conflicts_with_all = ["title", "body"])]
+        reset: bool,
+    },
+    /// Show or set where alerts go for wishlists without their own channels
+    Channels {
+        /// telegram[:CHAT], discord:URL, slack:URL, email:ADDRESS, ntfy:TOPIC,
+        /// gotify:URL?token=TOKEN or webhook:URL
+        channels: Vec<Channel>,
+        /// Go back to Telegram only
+        #[arg(long, conflicts_with = "channels")]
+        clear: bool,
+    },
+    /// Show or set when an alert already sent is repeated, and the daily digest
+    AlertRules {
+        /// Hours before the same alert is sent again (0 repeats it on every check)
+        #[arg(long)]
+        cooldown_hours: Option<u32>,
+        /// Repeat it sooner when the price drops this percent below the last alert
+        #[arg(long)]
+        step_percent: Option<f64>,
+        /// Batch the alerts into one message per day
+        #[arg(long, conflicts_with = "no_digest")]
+        digest: bool,
+        /// Send each alert right away
+        #[arg(long)]
+        no_digest: bool,
+        /// Local hour of the daily digest (0-23)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(0..24))]
+        digest_hour: Option<u32>,
+    },
+    /// Manage the chats allowed to use the Telegram bot (shows them when no action is given)
+    BotUsers {
+        #[command(subcommand)]
+        action: Option<BotUserCommand>,
+    },
+    /// Run the Telegram bot, answering /add, /list, /remove, /target, /check and /history
+    Bot {
+        /// Game used by /add instead of the default game
+        #[arg(long)]
+        game: Option<String>,
+    },
+    /// Copy the JSON files (wishlist, blueprints, history) into the configured storage
+    ImportJson,
+    /// Export the wishlist
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+        /// Write to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WishlistCommand {
+    /// Create a wishlist
+    Create {
+        name: String,
+        /// Send its alerts to this Telegram chat instead of TELEGRAM_CHAT_ID
+        #[arg(long, allow_negative_numbers = true)]
+        telegram_chat_id: Option<i64>,
+        /// Track prices without sending alerts
+        #[arg(long)]
+        no_alerts: bool,
+        /// Send its alerts here instead of the default channels (repeatable)
+        #[arg(long = "channel", value_name = "SPEC")]
+        channels: Vec<Channel>,
+    },
+    Rename {
+        from: String,
+        to: String,
+    },
+    /// Delete a wishlist and its cards
+    Delete {
+        name: String,
+    },
+    /// Copy cards from one wishlist to another
+    Copy {
+        from: String,
+        to: String,
+        /// Only this card
+        #[arg(long)]
+        card: Option<String>,
+        /// Only cards of this expansion
+        #[arg(long)]
+        expansion: Option<String>,
+    },
+    /// Change where and whether the alerts of a wishlist are sent
+    Alerts {
+        name: String,
+        #[arg(long, conflicts_with = "disable")]
+        enable: bool,
+        #[arg(long)]
+        disable: bool,
+        #[arg(long, conflicts_with = "default_chat", allow_negative_numbers = true)]
+        telegram_chat_id: Option<i64>,
+        /// Send to TELEGRAM_CHAT_ID again
+        #[arg(long)]
+        default_chat: bool,
+        /// Replace the channels of the wishlist (repeatable)
+        #[arg(
+            long = "channel",
+            value_name = "SPEC",
+            conflicts_with = "default_channels"
+        )]
+        channels: Vec<Channel>,
+        /// Send to the default channels again
+        #[arg(long)]
+        default_channels: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BotUserCommand {
+    /// Allow a chat to use the bot, with its own wishlist
+    Add {
+        #[arg(allow_negative_numbers = true)]
+        chat_id: i64,
+        #[arg(long)]
+        name: Option<String>,
+        /// Let the chat allow and revoke other chats
+        #[arg(long)]
+        admin: bool,
+    },
+    /// Revoke a chat; its wishlist is kept
+    Remove {
+        #[arg(allow_negative_numbers = true)]
+        chat_id: i64,
+    },
+}
+
+// Seleciona itens da wishlist; ao menos um critério é obrigatório
+#[derive(Args)]
+#[group(required = true, multiple = true)]
+pub struct FilterArgs {
+    /// Exact card name
+    card: Option<String>,
+    #[arg(long)]
+    expansion: Option<String>,
+    #[arg(long)]
+    blueprint_id: Option<u32>,
+}
+
+impl From<FilterArgs> for WishlistFilter {
+    fn from(args: FilterArgs) -> Self {
+        WishlistFilter {
+            card_name: args.card,
+            expansion_name: args.expansion,
+            blueprint_id: args.blueprint_id,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ListSort {
+    Name,
+    Price,
+    /// Closest to the target price first
+    Gap,
+}
+
+impl From<ListSort> for SortKey {
+    fn from(sort: ListSort) -> Self {
+        match sort {
+            ListSort::Name => SortKey::Name,
+            ListSort::Price => SortKey::Price,
+            ListSort::Gap => SortKey::Gap,
+        }
+    }
+}
+
+// Quais anúncios contam para o preço; sem nenhuma opção valem as padrão
+#[derive(Args)]
+pub struct PreferenceArgs {
+    /// Worst accepted condition: NM, SP, MP, PL, HP or PO
+    #[arg(long)]
+    min_condition: Option<Condition>,
+    /// Accepted language, e.g. en (repeatable or comma separated)
+    #[arg(long = "language", value_delimiter = ',')]
+    languages: Vec<String>,
+    /// any, foil or non-foil
+    #[arg(long)]
+    foil: Option<FoilPreference>,
+    /// Only sellers shipping through CardTrader Zero
+    #[arg(long)]
+    zero_only: bool,
+}
+
+impl PreferenceArgs {
+    fn into_preferences(self) -> Option<Preferences> {
+        if self.min_condition.is_none()
+            && self.languages.is_empty()
+            && self.foil.is_none()
+            && !self.zero_only
+        {
+            return None;
+        }
+        Some(Preferences {
+            min_condition: self.min_condition,
+            languages: self.languages,
+            foil: self.foil.unwrap_or_default(),
+            zero_only: self.zero_only,
+        })
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum DecklistFormat {
+    Text,
+    Dek,
+    Csv,
+}
+
+impl From<DecklistFormat> for DeckFormat {
+    fn from(format: DecklistFormat) -> Self {
+        match format {
+            DecklistFormat::Text => DeckFormat::Text,
+            DecklistFormat::Dek => DeckFormat::Dek,
+            DecklistFormat::Csv => DeckFormat::Csv,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+pub async fn run(command: Command, json: bool, list: Option<String>) -> Result<ExitCode, AppError> {
+    let shared_storage = storage::from_env()?;
+    let storage = shared_storage.as_ref();
+    if let Some(list) = &list {
+        wishlist::get_wishlist(storage, list)?;
+    }
+    // Comandos de uma wishlist só usam a padrão quando nenhuma foi escolhida
+    let selected = list.as_deref();
+    let list = selected.unwrap_or(DEFAULT_WISHLIST);
+    match command {
+        Command::SyncBlueprints { expansions, all } => {
+            let mode = if all {
+                SyncMode::All
+            } else if !expansions.is_empty() {
+                SyncMode::Expansions(expansions)
+            } else {
+                SyncMode::NewOnly
+            };
+            let api = CardTraderClient::from_env()?;
+            let summary =
+                sync::sync_blueprints(&api, storage, api.expansions().await?, mode).await?;
+            if json {
+                print_json(&summary)?;
+            } else {
+                println!("{}", describe_sync(&summary));
+            }
+            if !summary.failures.is_empty() {
+                return Ok(ExitCode::from(EXIT_PARTIAL));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Add {
+            card,
+            expansion,
+            collector_number,
+            game,
+            target_price,
+            quantity,
+            preferences,
+        } => {
+            if let Some(error) = wishlist::invalid_values(target_price, Some(quantity)) {
+                eprintln!("{}", error);
+                return Ok(ExitCode::from(EXIT_ERROR));
+            }
+            let game_id = game_or_default(game).await?;
+            let options = NewItem {
+                list: list.to_string(),
+                target_price,
+                quantity,
+                preferences: preferences.into_preferences(),
+            };
+            add(
+                storage,
+                &card,
+                game_id,
+                expansion.as_deref(),
+                collector_number.as_deref(),
+                options,
+                json,
+            )
+            .await
+        }
+        Command::Import {
+            file,
+            format,
+            game,
+            target_price,
+            discount,
+            preferences,
+        } => {
+            if let Some(error) = wishlist::invalid_values(target_price, None) {
+                eprintln!("{}", error);
+                return Ok(ExitCode::from(EXIT_ERROR));
+            }
+            let game_id = game_or_default(game).await?;
+            let target = match (target_price, discount) {
+                (Some(price), _) => DefaultTarget::Fixed(price),
+                (None, Some(percent)) => DefaultTarget::Discount(percent),
+                (None, None) => DefaultTarget::CurrentPrice,
+            };
+            let content = fs::read_to_string(&file)?;
+            let format = format
+                .map(DeckFormat::from)
+                .unwrap_or_else(|| DeckFormat::detect(&file, &content));
+            let lines = decklist::parse(format, &content)?;
+
+            let cache = BlueprintCache::new();
+            cache.load_from_storage(storage)?;
+            let api = CardTraderClient::from_env()?;
+            let source = pricing::source_from_env(&api)?;
+            let options = ImportOptions {
+                target,
+                preferences: preferences.into_preferences(),
+            };
+            let report =
+                decklist::import(storage, list, &cache, source, game_id, lines, options).await?;
+
+            if json {
+                print_json(&report)?;
+            } else {
+                print_import_report(&report);
+            }
+            if report.added.is_empty() {
+                return Ok(ExitCode::from(EXIT_NOT_FOUND));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Preferences { preferences, clear } => {
+            let mut settings = Settings::load()?;
+            if clear {
+                settings.preferences = Preferences::default();
+                settings.save()?;
+            } else if let Some(preferences) = preferences.into_preferences() {
+                settings.preferences = preferences;
+                settings.save()?;
+            }
+            if json {
+                print_json(&settings.preferences)?;
+            } else {
+                println!(
+                    "{}",
+                    tr!("preferences.default", preferences = settings.preferences)
+                );
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Locale { locale } => {
+            let mut settings = Settings::load()?;
+            match locale {
+                Some(locale) => {
+                    settings.locale = locale;
+                    settings.save()?;
+                    // A mensagem já sai no idioma novo
+                    println!(
+                        "{}",
+                        i18n::tr_in(locale, "locale.set", &[("locale", locale.to_string())])
+                    );
+                }
+                None if json => print_json(&settings.locale)?,
+                None => println!("{}", settings.locale),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Templates { title, body, reset } => templates(title, body, reset, json),
+        Command::Channels { channels, clear } => {
+            let mut settings = Settings::load()?;
+            if clear || !channels.is_empty() {
+                settings.alert_channels = channels;
+                settings.save()?;
+            }
+            if json {
+                print_json(&settings.alert_channels)?;
+            } else if settings.alert_channels.is_empty() {
+                println!("{}", tr!("channels.telegram_only"));
+            } else {
+                for channel in &settings.alert_channels {
+                    println!("{}", channel);
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::AlertRules {
+            cooldown_hours,
+            step_percent,
+            digest,
+            no_digest,
+            digest_hour,
+        } => {
+            let mut settings = Settings::load()?;
+            let rules = &mut settings.alert_rules;
+            if let Some(step) = step_percent {
+                if !(0.0..100.0).contains(&step) {
+                    return Err(AppError::Config(format!(
+                        "--step-percent must be between 0 and 100, got {}",
+                        step
+                    )));
+                }
+                rules.step_percent = step;
+            }
+            if let Some(hours) = cooldown_hours {
+                rules.cooldown_hours = hours;
+            }
+            if digest || no_digest {
+                rules.digest = digest;
+            }
+            if let Some(hour) = digest_hour {
+                rules.digest_hour = hour;
+            }
+            let changed = step_percent.is_some()
+                || cooldown_hours.is_some()
+                || digest
+                || no_digest
+                || digest_hour.is_some();
+            if changed {
+                settings.save()?;
+            }
+            let rules = &settings.alert_rules;
+            if json {
+                print_json(rules)?;
+            } else {
+                println!(
+                    "{}",
+                    tr!(
+                        "alert_rules.repeat",
+                        cooldown = rules.cooldown_hours,
+                        step = rules.step_percent
+                    )
+                );
+                if rules.digest {
+                    println!("{}", tr!("alert_rules.digest", hour = rules.digest_hour));
+                } else {
+                    println!("{}", tr!("alert_rules.no_digest"));
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Search { query, game, limit } => {
+            let game_id = game_or_default(game).await?;
+            let cache = BlueprintCache::new();
+            cache.load_from_storage(storage)?;
+
+            if let Some((code, number)) = search::parse_set_and_number(&query) {
+                let found = cache.find_by_set_and_number(game_id, &code, &number);
+                if !found.is_empty() {
+                    if json {
+                        print_json(&found)?;
+                    } else {
+                        for bp in &found {
+                            println!(
+                                "{} ({}) - {}",
+                                bp.card_name, bp.collector_number, bp.expansion_name
+                            );
+                        }
+                    }
+                    return Ok(ExitCode::SUCCESS);
+                }
+            }
+
+            let names: Vec<String> = cache
+                .search(game_id, &query, limit)
+                .into_iter()
+                .map(|hit| hit.name)
+                .collect();
+            if json {
+                print_json(&names)?;
+            } else {
+                for name in &names {
+                    println!("{}", name);
+                }
+            }
+            if names.is_empty() {
+                return Ok(ExitCode::from(EXIT_NOT_FOUND));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Games => {
+            let games = CardTraderClient::from_env()?.games().await?;
+            let default_game_id = Settings::load()?.default_game_id;
+            if json {
+                print_json(&games)?;
+            } else {
+                for game in &games {
+                    let marker = if Some(game.id) == default_game_id {
+                        " (default)"
+                    } else {
+                        ""
+                    };
+                    println!(
+                        "{}\t{}\t{}{}",
+                        game.id, game.name, game.display_name, marker
+                    );
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::SetGame { game } => {
+            let game = resolve_game(&game).await?;
+            let mut settings = Settings::load()?;
+            settings.default_game_id = Some(game.id);
+            settings.save()?;
+            println!("{}", tr!("game.default_set", game = game.display_name));
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Check => {
+            let api = CardTraderClient::from_env()?;
+            let report =
+                wishlist::check_wishlist_prices(storage, selected, pricing::source_from_env(&api)?)
+                    .await?;
+            if json {
+                print_json(&report)?;
+            } else {
+                println!(
+                    "{}",
+                    tr!(
+                        "check.summary",
+                        checked = report.checked,
+                        alerts = report.alerts.len(),
+                        suppressed = report.suppressed,
+                        no_listings = report.no_listings,
+                        failed = report.failures.len()
+                    )
+                );
+                if report.queued > 0 {
+                    println!("{}", tr!("check.queued", count = report.queued));
+                }
+            }
+            if !report.failures.is_empty() {
+                return Ok(ExitCode::from(EXIT_PARTIAL));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Watch { interval } => {
+            let api = CardTraderClient::from_env()?;
+            wishlist::continuous_check_prices(
+                storage,
+                selected,
+                pricing::source_from_env(&api)?,
+                Duration::from_secs(interval),
+            )
+            .await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::List { sort } => {
+            let mut wishlist = wishlist::load_wishlist(storage, list)?;
+            if let Some(sort) = sort {
+                wishlist::sort_items(&mut wishlist, sort.into());
+            }
+            if json {
+                print_json(&wishlist)?;
+            } else {
+                for item in &wishlist {
+                    match item.gap_percent() {
+                        Some(gap) => println!("{} ({:+.1}%)", describe_item(item), gap),
+                        None => println!("{}", describe_item(item)),
+                    }
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Edit {
+            filter,
+            target_price,
+            quantity,
+        } => {
+            if target_price.is_none() && quantity.is_none() {
+                eprintln!("{}", tr!("edit.nothing"));
+                return Ok(ExitCode::from(EXIT_ERROR));
+            }
+            if let Some(error) = wishlist::invalid_values(target_price, quantity) {
+                eprintln!("{}", error);
+                return Ok(ExitCode::from(EXIT_ERROR));
+            }
+            let filter = WishlistFilter::from(filter);
+            let matches: Vec<WishlistItem> = wishlist::load_wishlist(storage, list)?
+                .into_iter()
+                .filter(|item| filter.matches(item))
+                .collect();
+            if matches.len() > 1 {
+                eprintln!("{}", tr!("edit.ambiguous", count = matches.len()));
+                for item in &matches {
+                    eprintln!("  {}", describe_item(item));
+                }
+                return Ok(ExitCode::from(EXIT_AMBIGUOUS));
+            }
+
+            let changes = ItemChanges {
+                target_price,
+                quantity,
+            };
+            let edited = wishlist::edit_items(storage, list, &filter, &changes)?;
+            if json {
+                print_json(&edited)?;
+            } else {
+                for item in &edited {
+                    println!("{}", tr!("item.updated", item = describe_item(item)));
+                }
+            }
+            if edited.is_empty() {
+                eprintln!("{}", tr!("item.no_match"));
+                return Ok(ExitCode::from(EXIT_NOT_FOUND));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Remove { filter } => {
+            let removed = wishlist::remove_items(storage, list, &filter.into())?;
+            if json {
+                print_json(&removed)?;
+            } else {
+                for item in &removed {
+                    println!("{}", tr!("item.removed", item = describe_item(item)));
+                }
+            }
+            if removed.is_empty() {
+                eprintln!("{}", tr!("item.no_match"));
+                return Ok(ExitCode::from(EXIT_NOT_FOUND));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Dedup => {
+            let merged = wishlist::dedup_wishlist(storage, list)?;
+            if json {
+                print_json(&DedupReport {
+                    wishlist: list.to_string(),
+                    merged,
+                })?;
+            } else {
+                println!("{}", tr!("dedup.merged", count = merged));
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::History {
+            card,
+            expansion,
+            days,
+        } => history(storage, list, &card, expansion.as_deref(), days, json),
+        Command::Wishlists { action } => wishlists(storage, action, json),
+        Command::BotUsers { action } => bot_users(storage, action, json),
+        Command::Bot { game } => {
+            let api = CardTraderClient::from_env()?;
+            let cache = BlueprintCache::new();
+            cache.load_from_storage(storage)?;
+            let context = BotContext {
+                storage: Arc::clone(&shared_storage),
+                cache: Arc::new(cache),
+                source: pricing::source_from_env(&api)?,
+                list: list.to_string(),
+                game_id: game_or_default(game).await?,
+                owner_chat_id: bot::owner_chat_id()?,
+            };
+            eprintln!("{}", tr!("bot.running", list = list));
+            bot::run(context).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::ImportJson => {
+            if storage.name() == "json" {
+                eprintln!("{}", tr!("import_json.nothing"));
+                return Ok(ExitCode::from(EXIT_ERROR));
+            }
+            let copied = storage::copy_all(&JsonStorage::default(), storage)?;
+            println!(
+                "{}",
+                tr!("import_json.done", count = copied, storage = storage.name())
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Export { format, output } => {
+            let wishlist = wishlist::load_wishlist(storage, list)?;
+            let content = match format {
+                ExportFormat::Json => serde_json::to_string_pretty(&wishlist)?,
+                ExportFormat::Csv => wishlist_to_csv(&wishlist),
+            };
+            match output {
+                Some(path) => fs::write(path, content)?,
+                None => println!("{}", content),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn wishlists(
+    storage: &dyn Storage,
+    action: Option<WishlistCommand>,
+    json: bool,
+) -> Result<ExitCode, AppError> {
+    match action {
+        None => {
+            let lists = storage.wishlists()?;
+            if json {
+                print_json(&lists)?;
+                return Ok(ExitCode::SUCCESS);
+            }
+            for list in &lists {
+                println!(
+                    "{}\t{}\t{}",
+                    list.name,
+                    tr!(
+                        "wishlist.cards",
+                        count = storage.load_wishlist(&list.name)?.len()
+                    ),
+                    describe_alerts(&list.alerts)
+                );
+            }
+        }
+        Some(WishlistCommand::Create {
+            name,
+            telegram_chat_id,
+            no_alerts,
+            channels,
+        }) => {
+            let mut info = WishlistInfo::new(&name);
+            info.alerts.enabled = !no_alerts;
+            info.alerts.telegram_chat_id = telegram_chat_id;
+            info.alerts.channels = channels;
+            wishlist::create_wishlist(storage, &info)?;
+            println!("{}", tr!("wishlist.created", name = name));
+        }
+        Some(WishlistCommand::Rename { from, to }) => {
+            wishlist::rename_wishlist(storage, &from, &to)?;
+            println!("{}", tr!("wishlist.renamed", from = from, to = to));
+        }
+        Some(WishlistCommand::Delete { name }) => {
+            wishlist::delete_wishlist(storage, &name)?;
+            println!("{}", tr!("wishlist.deleted", name = name));
+        }
+        Some(WishlistCommand::Copy {
+            from,
+            to,
+            card,
+            expansion,
+        }) => {
+            wishlist::get_wishlist(storage, &from)?;
+            let filter = WishlistFilter {
+                card_name: card,
+                expansion_name: expansion,
+                blueprint_id: None,
+            };
+            let (copied, duplicates) = wishlist::copy_items(storage, &from, &to, &filter)?;
+            for item in &duplicates {
+                eprintln!(
+                    "{}",
+                    tr!("wishlist.already_in", list = to, item = describe_item(item))
+                );
+            }
+            println!(
+                "{}",
+                tr!("wishlist.copied", count = copied, from = from, to = to)
+            );
+        }
+        Some(WishlistCommand::Alerts {
+            name,
+            enable,
+            disable,
+            telegram_chat_id,
+            default_chat,
+            channels,
+            default_channels,
+        }) => {
+            let mut info = wishlist::get_wishlist(storage, &name)?;
+            if enable || disable {
+                info.alerts.enabled = enable;
+            }
+            if telegram_chat_id.is_some() || default_chat {
+                info.alerts.telegram_chat_id = telegram_chat_id;
+            }
+            if !channels.is_empty() || default_channels {
+                info.alerts.channels = channels;
+            }
+            storage.save_wishlist_info(&info)?;
+            if json {
+                print_json(&info)?;
+            } else {
+                println!("{}: {}", info.name, describe_alerts(&info.alerts));
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn templates(
+    title: Option<String>,
+    body: Option<String>,
+    reset: bool,
+    json: bool,
+) -> Result<ExitCode, AppError> {
+    let mut settings = Settings::load()?;
+    if reset {
+        settings.alert_templates = AlertTemplates::default();
+        settings.save()?;
+    } else if title.is_some() || body.is_some() {
+        let mut custom = settings.alert_templates.clone();
+        custom.title = title.or(custom.title);
+        custom.body = body.or(custom.body);
+        // Só salva templates que funcionam
+        Templates::new(settings.locale, &custom)?;
+        settings.alert_templates = custom;
+        settings.save()?;
+    }
+
+    let current = AlertTemplates {
+        title: Some(
+            settings
+                .alert_templates
+                .title
+                .unwrap_or_else(|| i18n::message(settings.locale, "alert.title").to_string()),
+        ),
+        body: Some(
+            settings
+                .alert_templates
+                .body
+                .unwrap_or_else(|| i18n::message(settings.locale, "alert.body").to_string()),
+        ),
+    };
+    if json {
+        print_json(&current)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+    let templates = Templates::new(settings.locale, &current)?;
+    let sample = sample_alert();
+    println!("{}", tr!("templates.title"));
+    println!("{}\n", current.title.unwrap_or_default());
+    println!("{}", tr!("templates.body"));
+    println!("{}\n", current.body.unwrap_or_default());
+    println!("{}", tr!("templates.preview"));
+    println!(
+        "{}\n",
+        templates.title(std::slice::from_ref(&sample), &Plain)
+    );
+    println!("{}", templates.body(&sample, &Plain));
+    Ok(ExitCode::SUCCESS)
+}
+
+// Alerta de exemplo para a prévia dos templates
+fn sample_alert() -> Alert {
+    Alert {
+        wishlist: DEFAULT_WISHLIST.to_string(),
+        card_name: "Lightning Bolt".to_string(),
+        version: String::new(),
+        collector_number: "146".to_string(),
+        expansion_name: "Magic 2011".to_string(),
+        blueprint_id: None,
+        target_price: 1.5,
+        price: 1.2,
+        currency: "EUR".to_string(),
+        link: slug::card_url("Lightning Bolt", "", "Magic 2011"),
+        image_url: None,
+    }
+}
+
+// "alerts off", "alerts on" (canais padrão) ou para onde vão
+fn describe_alerts(alerts: &AlertSettings) -> String {
+    if !alerts.enabled {
+        return tr!("alerts.off");
+    }
+    if alerts.channels.is_empty() {
+        return match alerts.telegram_chat_id {
+            Some(chat_id) => tr!("alerts.to_chat", chat_id = chat_id),
+            None => tr!("alerts.on"),
+        };
+    }
+    let channels: Vec<String> = alerts
+        .channels
+        .iter()
+        .map(|channel| match channel {
+            // O chat da wishlist vale para o canal do Telegram sem chat próprio
+            Channel::Telegram { chat_id: None } => Channel::Telegram {
+                chat_id: alerts.telegram_chat_id,
+            }
+            .to_string(),
+            channel => channel.to_string(),
+        })
+        .collect();
+    tr!("alerts.to", channels = channels.join(", "))
+}
+
+fn bot_users(
+    storage: &dyn Storage,
+    action: Option<BotUserCommand>,
+    json: bool,
+) -> Result<ExitCode, AppError> {
+    match action {
+        None => {
+            let users = storage.bot_users()?;
+            if json {
+                print_json(&users)?;
+                return Ok(ExitCode::SUCCESS);
+            }
+            if let Some(owner) = bot::owner_chat_id()? {
+                println!("{}\t{}\tadmin", owner, tr!("bot_users.owner"));
+            }
+            for user in &users {
+                println!(
+                    "{}\t{}\t{}\t{}",
+                    user.chat_id,
+                    user.name,
+                    if user.admin { "admin" } else { "user" },
+                    user.wishlist
+                );
+            }
+        }
+        Some(BotUserCommand::Add {
+            chat_id,
+            name,
+            admin,
+        }) => {
+            let user = bot::add_user(storage, chat_id, name.as_deref(), admin)?;
+            // "admin" vazio esconde o trecho de administrador
+            println!(
+                "{}",
+                tr!(
+                    "bot_users.added",
+                    name = user.name,
+                    chat_id = user.chat_id,
+                    admin = if user.admin { "yes" } else { "" },
+                    list = user.wishlist
+                )
+            );
+        }
+        Some(BotUserCommand::Remove { chat_id }) => match bot::remove_user(storage, chat_id)? {
+            Some(user) => println!(
+                "{}",
+                tr!(
+                    "bot_users.removed",
+                    name = user.name,
+                    chat_id = user.chat_id,
+                    list = user.wishlist
+                )
+            ),
+            None => {
+                eprintln!("{}", tr!("bot_users.not_allowed", chat_id = chat_id));
+                return Ok(ExitCode::from(EXIT_NOT_FOUND));
+            }
+        },
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn resolve_game(query: &str) -> Result<Game, AppError> {
+    let games = CardTraderClient::from_env()?.games().await?;
+    game::find_game(&games, query)
+        .cloned()
+        .ok_or_else(|| AppError::Config(format!("unknown game {:?}, see `games`", query)))
+}
+
+async fn game_or_default(game: Option<String>) -> Result<Option<u32>, AppError> {
+    match game {
+        Some(game) => Ok(Some(resolve_game(&game).await?.id)),
+        None => Ok(Settings::load()?.default_game_id),
+    }
+}
+
+struct NewItem {
+    list: String,
+    target_price: Option<f64>,
+    quantity: u32,
+    preferences: Option<Preferences>,
+}
+
+async fn add(
+    storage: &dyn Storage,
+    card: &str,
+    game_id: Option<u32>,
+    expansion: Option<&str>,
+    collector_number: Option<&str>,
+    options: NewItem,
+    json: bool,
+) -> Result<ExitCode, AppError> {
+    let cache = BlueprintCache::new();
+    cache.load_from_storage(storage)?;
+
+    // --expansion aceita o nome ou o código da expansão
+    let candidates = cache.find_versions(game_id, card, expansion, collector_number);
+
+    let blueprint = match candidates.as_slice() {
+        [] => {
+            eprintln!("{}", tr!("add.not_found", card = card));
+            let suggestions = cache.search(game_id, card, 5);
+            if !suggestions.is_empty() {
+                eprintln!("{}", tr!("add.did_you_mean"));
+                for hit in suggestions {
+                    eprintln!("  {}", hit.name);
+                }
+            }
+            return Ok(ExitCode::from(EXIT_NOT_FOUND));
+        }
+        [blueprint] => blueprint,
+        _ => {
+            eprintln!(
+                "{}",
+                tr!("add.ambiguous", card = card, count = candidates.len())
+            );
+            for bp in &candidates {
+                eprintln!(
+                    "  {} ({}) - {}",
+                    bp.card_name, bp.collector_number, bp.expansion_name
+                );
+            }
+            return Ok(ExitCode::from(EXIT_AMBIGUOUS));
+        }
+    };
+
+    let mut item = match options.target_price {
+        Some(target_price) => WishlistItem::from_blueprint(blueprint, target_price),
+        None => {
+            let api = CardTraderClient::from_env()?;
+            let source = pricing::source_from_env(&api)?;
+            match wishlist::quote_item(
+                source.as_ref(),
+                blueprint,
+                options.quantity,
+                options.preferences.clone(),
+            )
+            .await?
+            {
+                Some(item) => item,
+                None => {
+                    eprintln!("{}", tr!("add.no_listings", card = card));
+                    return Ok(ExitCode::from(EXIT_ERROR));
+                }
+            }
+        }
+    };
+    item.quantity = options.quantity;
+    item.preferences = options.preferences;
+
+    if !wishlist::add_to_wishlist(storage, &options.list, item.clone())? {
+        eprintln!("{}", tr!("add.duplicate", item = describe_item(&item)));
+        return Ok(ExitCode::SUCCESS);
+    }
+    if json {
+        print_json(&item)?;
+    } else {
+        println!("{}", tr!("item.added", item = describe_item(&item)));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[derive(Serialize)]
+struct DedupReport {
+    wishlist: String,
+    merged: usize,
+}
+
+#[derive(Serialize)]
+struct CardHistory {
+    item: WishlistItem,
+    stats: Option<PriceStats>,
+    percent_change: Option<f64>,
+    observations: Vec<PriceObservation>,
+}
+
+fn history(
+    storage: &dyn Storage,
+    list: &str,
+    card: &str,
+    expansion: Option<&str>,
+    days: Option<i64>,
+    json: bool,
+) -> Result<ExitCode, AppError> {
+    let items: Vec<WishlistItem> = wishlist::load_wishlist(storage, list)?
+        .into_iter()
+        .filter(|item| item.card_name.eq_ignore_ascii_case(card))
+        .filter(|item| expansion.is_none_or(|e| item.expansion_name.eq_ignore_ascii_case(e)))
+        .collect();
+    if items.is_empty() {
+        eprintln!("{}", tr!("item.no_match", card = card));
+        return Ok(ExitCode::from(EXIT_NOT_FOUND));
+    }
+
+    let since = days.map(|days| Utc::now() - TimeDelta::days(days));
+    let mut histories = Vec::new();
+    for item in items {
+        let observations = storage.observations(&item.key(), since)?;
+        let stats = PriceStats::from_observations(&observations);
+        histories.push(CardHistory {
+            percent_change: stats.as_ref().and_then(PriceStats::percent_change),
+            stats,
+            item,
+            observations,
+        });
+    }
+
+    if json {
+        print_json(&histories)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+    for history in &histories {
+        println!("{}", describe_item(&history.item));
+        match &history.stats {
+            Some(stats) => println!(
+                "{}",
+                tr!(
+                    "history.stats",
+                    count = stats.count,
+                    min = pricing::format_amount(stats.min, &stats.currency),
+                    max = pricing::format_amount(stats.max, &stats.currency),
+                    average = pricing::format_amount(stats.average, &stats.currency),
+                    change = history
+                        .percent_change
+                        .map(|change| format!("{:+.1}%", change))
+                        .unwrap_or_else(|| "-".to_string())
+                )
+            ),
+            None => println!("{}", tr!("history.empty")),
+        }
+        for observation in &history.observations {
+            println!(
+                "  {}  {:.2} {}  ({})",
+                observation.timestamp.format("%Y-%m-%d %H:%M"),
+                observation.price,
+                observation.currency,
+                observation.source
+            );
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+pub fn print_import_report(report: &ImportReport) {
+    for item in &report.added {
+        println!("{}", tr!("item.added", item = describe_item(item)));
+    }
+    for ambiguous in &report.ambiguous {
+        eprintln!(
+            "{}",
+            tr!(
+                "import.ambiguous",
+                line = ambiguous.line.line,
+                card = ambiguous.line.name,
+                count = ambiguous.candidates.len()
+            )
+        );
+        for bp in &ambiguous.candidates {
+            eprintln!(
+                "  {} ({}) - {}",
+                bp.card_name, bp.collector_number, bp.expansion_name
+            );
+        }
+    }
+    for item in &report.duplicates {
+        eprintln!("{}", tr!("import.duplicate", item = describe_item(item)));
+    }
+    for line in &report.unresolved {
+        eprintln!(
+            "{}",
+            tr!("import.not_found", line = line.line, card = line.name)
+        );
+    }
+    for line in &report.no_price {
+        eprintln!(
+            "{}",
+            tr!("import.no_price", line = line.line, card = line.name)
+        );
+    }
+    println!(
+        "{}",
+        tr!(
+            "import.summary",
+            added = report.added.len(),
+            ambiguous = report.ambiguous.len(),
+            not_found = report.unresolved.len(),
+            no_price = report.no_price.len()
+        )
+    );
+}
+
+pub fn describe_sync(summary: &SyncSummary) -> String {
+    let mut text = tr!(
+        "sync.summary",
+        expansions = summary.expansions,
+        added = summary.added,
+        changed = summary.changed,
+        removed = summary.removed
+    );
+    for failure in &summary.failures {
+        text.push('\n');
+        text.push_str(&tr!("sync.failed", failure = failure));
+    }
+    text
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), AppError> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn wishlist_to_csv(wishlist: &[WishlistItem]) -> String {
+    let mut csv =
+        String::from("card_name,expansion_name,version,collector_number,target_price,last_price,lowest_price,blueprint_id,quantity\n");
+    for item in wishlist {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            csv_field(&item.card_name),
+            csv_field(&item.expansion_name),
+            csv_field(&item.version),
+            csv_field(&item.collector_number),
+            item.target_price,
+            item.last_price.map(|p| p.to_string()).unwrap_or_default(),
+            item.lowest_price.map(|p| p.to_string()).unwrap_or_default(),
+            item.blueprint_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            item.quantity
+        ));
+    }
+    csv
+}
@@ -0,0 +1,518 @@
+use crate::api::blueprint::BlueprintData;
+use crate::cache::BlueprintCache;
+use crate::error::AppError;
+use crate::preferences::Preferences;
+use crate::pricing::{PriceOutcome, PriceSource};
+use crate::storage::Storage;
+use crate::tr;
+use crate::wishlist::{self, WishlistItem};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DeckFormat {
+    // Texto simples e exportação do MTG Arena
+    Text,
+    // XML do MTGO
+    Dek,
+    // CSV do Moxfield / Archidekt
+    Csv,
+}
+
+impl DeckFormat {
+    // Pela extensão do arquivo, ou pelo conteúdo quando ela não ajuda
+    pub fn detect(path: &Path, content: &str) -> DeckFormat {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        match extension.as_deref() {
+            Some("dek") | Some("xml") => DeckFormat::Dek,
+            Some("csv") => DeckFormat::Csv,
+            _ if content.trim_start().starts_with('<') => DeckFormat::Dek,
+            _ => DeckFormat::Text,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub struct DeckLine {
+    // Linha no arquivo, começando em 1
+    pub line: usize,
+    pub quantity: u32,
+    pub name: String,
+    pub set_code: Option<String>,
+    pub collector_number: Option<String>,
+}
+
+pub fn parse(format: DeckFormat, content: &str) -> Result<Vec<DeckLine>, AppError> {
+    match format {
+        DeckFormat::Text => Ok(parse_text(content)),
+        DeckFormat::Dek => Ok(parse_dek(content)),
+        DeckFormat::Csv => parse_csv(content),
+    }
+}
+
+// Seções do Arena e de outros sites; não são cartas
+const SECTION_HEADERS: &[&str] = &[
+    "deck",
+    "main",
+    "mainboard",
+    "sideboard",
+    "commander",
+    "companion",
+    "maybeboard",
+    "about",
+];
+
+lazy_static! {
+    // "4 Lightning Bolt (M11) 146", "4x Lightning Bolt [M11]", "Lightning Bolt"
+    static ref TEXT_LINE: Regex = Regex::new(
+        r"^(?:(\d+)x?\s+)?(.+?)(?:\s+[\(\[]([A-Za-z0-9]+)[\)\]](?:\s+([A-Za-z0-9-]+))?)?(?:\s+\*F\*)?$",
+    )
+    .unwrap();
+    // <Cards CatID="12345" Quantity="4" Sideboard="false" Name="Lightning Bolt" />
+    static ref DEK_CARD: Regex = Regex::new(r"<Cards\b([^>]*)>").unwrap();
+    static ref DEK_ATTRIBUTE: Regex = Regex::new(r#"(\w+)\s*=\s*"([^"]*)""#).unwrap();
+}
+
+fn parse_text(content: &str) -> Vec<DeckLine> {
+    let mut lines = Vec::new();
+    let mut in_about = false;
+    for (index, raw) in content.lines().enumerate() {
+        let text = raw.trim();
+        if text.is_empty() {
+            in_about = false;
+            continue;
+        }
+        if text.starts_with("//") || text.starts_with('#') {
+            continue;
+        }
+        let header = text.trim_end_matches(':').to_lowercase();
+        if SECTION_HEADERS.contains(&header.as_str()) {
+            in_about = header == "about";
+            continue;
+        }
+        // A seção About do Arena só tem o nome do deck
+        if in_about {
+            continue;
+        }
+        if let Some(captures) = TEXT_LINE.captures(text) {
+            lines.push(DeckLine {
+                line: index + 1,
+                quantity: captures
+                    .get(1)
+                    .and_then(|quantity| quantity.as_str().parse().ok())
+                    .unwrap_or(1),
+                name: captures[2].trim().to_string(),
+                set_code: captures.get(3).map(|code| code.as_str().to_string()),
+                collector_number: captures.get(4).map(|number| number.as_str().to_string()),
+            });
+        }
+    }
+    lines
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn parse_dek(content: &str) -> Vec<DeckLine> {
+    let mut lines = Vec::new();
+    for captures in DEK_CARD.captures_iter(content) {
+        let start = captures.get(0).unwrap().start();
+        let attributes: HashMap<String, String> = DEK_ATTRIBUTE
+            .captures_iter(&captures[1])
+            .map(|attribute| (attribute[1].to_lowercase(), unescape_xml(&attribute[2])))
+            .collect();
+        let Some(name) = attributes.get("name") else {
+            continue;
+        };
+        lines.push(DeckLine {
+            line: content[..start].matches('\n').count() + 1,
+            quantity: attributes
+                .get("quantity")
+                .and_then(|quantity| quantity.parse().ok())
+                .unwrap_or(1),
+            name: name.clone(),
+            set_code: None,
+            collector_number: None,
+        });
+    }
+    lines
+}
+
+// Separa uma linha de CSV respeitando campos entre aspas
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn find_column(header: &[String], names: &[&str]) -> Option<usize> {
+    header
+        .iter()
+        .position(|column| names.contains(&column.trim().to_lowercase().as_str()))
+}
+
+// Moxfield: Count,Name,Edition,...,Collector Number
+// Archidekt: Quantity,Name,...,Edition Code,...,Collector Number
+fn parse_csv(content: &str) -> Result<Vec<DeckLine>, AppError> {
+    let mut rows = content.lines().enumerate();
+    let header = match rows.next() {
+        Some((_, header)) => split_csv_line(header),
+        None => return Ok(Vec::new()),
+    };
+    let name = find_column(&header, &["name", "card name", "card"])
+        .ok_or_else(|| AppError::Config("the CSV has no Name column".to_string()))?;
+    let quantity = find_column(&header, &["count", "quantity", "qty"]);
+    let set_code = find_column(&header, &["edition code", "set code", "edition", "set"]);
+    let collector_number = find_column(&header, &["collector number", "collector_number"]);
+
+    let mut lines = Vec::new();
+    for (index, row) in rows {
+        if row.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(row);
+        let field = |column: Option<usize>| {
+            column
+                .and_then(|column| fields.get(column))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let Some(card_name) = field(Some(name)) else {
+            continue;
+        };
+        lines.push(DeckLine {
+            line: index + 1,
+            quantity: field(quantity)
+                .and_then(|quantity| quantity.parse().ok())
+                .unwrap_or(1),
+            name: card_name.to_string(),
+            set_code: field(set_code).map(str::to_string),
+            collector_number: field(collector_number).map(str::to_string),
+        });
+    }
+    Ok(lines)
+}
+
+pub enum Resolution {
+    Found(Box<BlueprintData>),
+    Ambiguous(Vec<BlueprintData>),
+    NotFound,
+}
+
+// Procura a carta pelo nome exato ou, se não achar, pelo nome normalizado
+// (acentos, maiúsculas); depois filtra pela expansão e pelo número.
+pub fn resolve(cache: &BlueprintCache, game_id: Option<u32>, line: &DeckLine) -> Resolution {
+    let mut versions = cache.get_blueprints_by_name(game_id, &line.name);
+    if versions.is_none() {
+        versions = cache
+            .search(game_id, &line.name, 1)
+            .into_iter()
+            .find(|hit| hit.score == 0)
+            .and_then(|hit| cache.get_blueprints_by_name(game_id, &hit.name));
+    }
+    let Some(mut versions) = versions else {
+        return Resolution::NotFound;
+    };
+
+    if let Some(code) = &line.set_code {
+        let expansion_ids: Vec<u32> = cache
+            .find_expansions_by_code(game_id, code)
+            .iter()
+            .map(|expansion| expansion.id)
+            .collect();
+        versions.retain(|bp| {
+            bp.expansion_id
+                .is_some_and(|id| expansion_ids.contains(&id))
+                || bp.expansion_name.eq_ignore_ascii_case(code)
+        });
+    }
+    if let Some(number) = &line.collector_number {
+        versions.retain(|bp| bp.collector_number.eq_ignore_ascii_case(number));
+    }
+
+    match versions.len() {
+        0 => Resolution::NotFound,
+        1 => Resolution::Found(Box::new(versions.remove(0))),
+        _ => Resolution::Ambiguous(versions),
+    }
+}
+
+// Preço desejado dos itens importados
+#[derive(Clone, Copy)]
+pub enum DefaultTarget {
+    CurrentPrice,
+    // Porcentagem abaixo do preço atual
+    Discount(f64),
+    Fixed(f64),
+}
+
+pub struct ImportOptions {
+    pub target: DefaultTarget,
+    // Preferências dos itens importados; None usa as padrão
+    pub preferences: Option<Preferences>,
+}
+
+#[derive(Serialize)]
+pub struct AmbiguousLine {
+    pub line: DeckLine,
+    pub candidates: Vec<BlueprintData>,
+}
+
+#[derive(Serialize, Default)]
+pub struct ImportReport {
+    pub added: Vec<WishlistItem>,
+    pub ambiguous: Vec<AmbiguousLine>,
+    pub unresolved: Vec<DeckLine>,
+    // Sem anúncios ou com falha na consulta: não há preço atual para calcular
+    // o preço desejado
+    pub no_price: Vec<DeckLine>,
+    // Já estavam na wishlist
+    pub duplicates: Vec<WishlistItem>,
+}
+
+// Resolve as linhas, consulta os preços quando necessário e grava os itens
+// encontrados numa única escrita. Linhas repetidas da mesma versão (main e
+// sideboard, por exemplo) viram um item só, com as quantidades somadas.
+pub async fn import(
+    storage: &dyn Storage,
+    list: &str,
+    cache: &BlueprintCache,
+    source: Arc<dyn PriceSource>,
+    game_id: Option<u32>,
+    lines: Vec<DeckLine>,
+    options: ImportOptions,
+) -> Result<ImportReport, AppError> {
+    if let DefaultTarget::Discount(percent) = options.target {
+        // 100% ou mais daria um preço desejado zero ou negativo
+        if !(0.0..100.0).contains(&percent) {
+            return Err(AppError::Config(format!(
+                "the discount must be at least 0 and less than 100 percent, got {}",
+                percent
+            )));
+        }
+    }
+    let mut report = ImportReport::default();
+    let mut items: Vec<WishlistItem> = Vec::new();
+    let mut item_lines: Vec<DeckLine> = Vec::new();
+    for line in lines {
+        match resolve(cache, game_id, &line) {
+            Resolution::Found(blueprint) => {
+                match items
+                    .iter_mut()
+                    .find(|item| item.blueprint_id == Some(blueprint.blueprint_id))
+                {
+                    Some(item) => item.quantity += line.quantity,
+                    None => {
+                        let mut item = WishlistItem::from_blueprint(&blueprint, 0.0);
+                        item.quantity = line.quantity;
+                        item.preferences = options.preferences.clone();
+                        items.push(item);
+                        item_lines.push(line);
+                    }
+                }
+            }
+            Resolution::Ambiguous(candidates) => {
+                report.ambiguous.push(AmbiguousLine { line, candidates })
+            }
+            Resolution::NotFound => report.unresolved.push(line),
+        }
+    }
+
+    if let DefaultTarget::Fixed(price) = options.target {
+        for item in items.iter_mut() {
+            item.target_price = price;
+        }
+        report.added = items;
+    } else {
+        let outcomes = wishlist::fetch_outcomes(&items, &source).await?;
+        let mut priced = vec![false; items.len()];
+        for (index, outcome) in outcomes {
+            let item = &mut items[index];
+            match outcome {
+                Ok(PriceOutcome::Quote(quote)) => {
+                    item.target_price = match options.target {
+                        DefaultTarget::Discount(percent) => {
+                            (quote.amount * (1.0 - percent / 100.0) * 100.0).round() / 100.0
+                        }
+                        _ => quote.amount,
+                    };
+                    item.record_quote(&quote);
+                    priced[index] = true;
+                }
+                Ok(PriceOutcome::NoListings) => {}
+                Err(e) => eprintln!(
+                    "{}",
+                    tr!("check.fetch_failed", card = item.card_name, error = e)
+                ),
+            }
+        }
+        for ((item, line), priced) in items.into_iter().zip(item_lines).zip(priced) {
+            if priced {
+                report.added.push(item);
+            } else {
+                report.no_price.push(line);
+            }
+        }
+    }
+
+    // Cartas que já estavam na wishlist não são repetidas
+    let duplicates = wishlist::add_items(storage, list, report.added.clone())?;
+    report.added.retain(|item| {
+        !duplicates
+            .iter()
+            .any(|d| d.blueprint_id == item.blueprint_id)
+    });
+    report.duplicates = duplicates;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::expansion::Expansion;
+    use crate::pricing::FixturePriceSource;
+    use crate::storage::SqliteStorage;
+    use crate::wishlist::DEFAULT_WISHLIST;
+    use std::collections::HashMap;
+
+    fn line(line: usize, quantity: u32, name: &str) -> DeckLine {
+        DeckLine {
+            line,
+            quantity,
+            name: name.to_string(),
+            set_code: None,
+            collector_number: None,
+        }
+    }
+
+    #[test]
+    fn parses_text_and_arena_exports() {
+        let deck = "About\nName Burn\n\nDeck\n4 Lightning Bolt (M11) 146\n\
+                    2x Goblin Guide\n// comentário\nSol Ring\n\nSideboard\n1 Fire // Ice (MH3) 290 *F*\n";
+        let lines = parse(DeckFormat::Text, deck).unwrap();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            DeckLine {
+                line: 5,
+                quantity: 4,
+                name: "Lightning Bolt".to_string(),
+                set_code: Some("M11".to_string()),
+                collector_number: Some("146".to_string()),
+            }
+        );
+        assert_eq!(lines[1], line(6, 2, "Goblin Guide"));
+        assert_eq!(lines[2], line(8, 1, "Sol Ring"));
+        assert_eq!(lines[3].name, "Fire // Ice");
+        assert_eq!(lines[3].collector_number.as_deref(), Some("290"));
+    }
+
+    #[test]
+    fn parses_mtgo_dek_and_csv_exports() {
+        let dek = r#"<?xml version="1.0" encoding="utf-8"?>
+<Deck xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <NetDeckID>0</NetDeckID>
+  <Cards CatID="37904" Quantity="4" Sideboard="false" Name="Lightning Bolt" />
+  <Cards CatID="1" Quantity="1" Sideboard="true" Name="Jace, the Mind Sculptor" />
+</Deck>"#;
+        let lines = parse(DeckFormat::Dek, dek).unwrap();
+        assert_eq!(lines[0], line(4, 4, "Lightning Bolt"));
+        assert_eq!(lines[1].name, "Jace, the Mind Sculptor");
+
+        let moxfield = "\"Count\",\"Tradelist Count\",\"Name\",\"Edition\",\"Collector Number\"\n\
+                        \"4\",\"0\",\"Jace, the Mind Sculptor\",\"a25\",\"62\"\n";
+        let lines = parse(DeckFormat::Csv, moxfield).unwrap();
+        assert_eq!(lines[0].quantity, 4);
+        assert_eq!(lines[0].name, "Jace, the Mind Sculptor");
+        assert_eq!(lines[0].set_code.as_deref(), Some("a25"));
+
+        assert!(parse(DeckFormat::Csv, "Quantity,Card\n1,Sol Ring\n").is_ok());
+        assert!(parse(DeckFormat::Csv, "Quantity,Set\n1,M11\n").is_err());
+    }
+
+    #[test]
+    fn resolves_by_name_set_and_number() {
+        let cache = BlueprintCache::new();
+        cache.load_expansions(vec![Expansion {
+            id: 10,
+            name: "Magic 2011".to_string(),
+            code: Some("M11".to_string()),
+            game_id: Some(1),
+        }]);
+        let bolt = |blueprint_id, expansion_id, collector_number: &str| BlueprintData {
+            blueprint_id,
+            card_name: "Lightning Bolt".to_string(),
+            collector_number: collector_number.to_string(),
+            expansion_id: Some(expansion_id),
+            game_id: Some(1),
+            ..BlueprintData::default()
+        };
+        cache.load_blueprints(vec![bolt(1, 10, "146"), bolt(2, 20, "1")]);
+
+        let mut wanted = line(1, 4, "lightning bolt");
+        assert!(matches!(
+            resolve(&cache, None, &wanted),
+            Resolution::Ambiguous(candidates) if candidates.len() == 2
+        ));
+        wanted.set_code = Some("m11".to_string());
+        assert!(matches!(
+            resolve(&cache, None, &wanted),
+            Resolution::Found(bp) if bp.blueprint_id == 1
+        ));
+        assert!(matches!(
+            resolve(&cache, None, &line(2, 1, "Lightning Boltz")),
+            Resolution::NotFound
+        ));
+    }
+
+    #[tokio::test]
+    async fn discounts_outside_0_to_100_are_rejected() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        let source: Arc<dyn PriceSource> = Arc::new(FixturePriceSource::new(HashMap::new()));
+        for percent in [-5.0, 100.0, 150.0] {
+            let options = ImportOptions {
+                target: DefaultTarget::Discount(percent),
+                preferences: None,
+            };
+            let result = import(
+                &storage,
+                DEFAULT_WISHLIST,
+                &BlueprintCache::new(),
+                Arc::clone(&source),
+                None,
+                vec![line(1, 1, "Sol Ring")],
+                options,
+            )
+            .await;
+            assert!(matches!(result, Err(AppError::Config(_))), "{}", percent);
+        }
+    }
+}
@@ -1,25 +1,151 @@
+use std::error::Error;
 use std::fmt;
+use std::io;
+
+type BoxError = Box<dyn Error + Send + Sync>;
 
 #[derive(Debug)]
-pub struct CustomError {
-    message: String,
+pub enum AppError {
+    Http(reqwest::Error),
+    MissingEnv {
+        var: String,
+        source: std::env::VarError,
+    },
+    Auth(String),
+    Config(String),
+    Json(serde_json::Error),
+    Database(rusqlite::Error),
+    Io(io::Error),
+    Browser(BoxError),
+    Telegram(teloxide::RequestError),
+    CardNotFound(String),
+    Prompt(inquire::InquireError),
+    Task(tokio::task::JoinError),
+    // Falha ao entregar alertas por um canal (Discord, e-mail, ntfy...)
+    Notification {
+        channel: &'static str,
+        source: BoxError,
+    },
 }
 
-impl CustomError {
-    pub fn new(msg: &str) -> CustomError {
-        CustomError {
-            message: msg.to_string(),
+impl AppError {
+    pub fn missing_env(var: &str, source: std::env::VarError) -> AppError {
+        AppError::MissingEnv {
+            var: var.to_string(),
+            source,
+        }
+    }
+
+    pub fn notification<E: Into<BoxError>>(channel: &'static str, error: E) -> AppError {
+        AppError::Notification {
+            channel,
+            source: error.into(),
+        }
+    }
+
+    pub fn browser<E: Into<BoxError>>(error: E) -> AppError {
+        AppError::Browser(error.into())
+    }
+
+    // Erros transitórios (rede, navegador, limites de taxa) valem uma nova tentativa
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AppError::Http(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status()
+                        .is_some_and(|status| status.is_server_error() || status.as_u16() == 429)
+            }
+            AppError::Browser(_) => true,
+            AppError::Telegram(e) => matches!(
+                e,
+                teloxide::RequestError::Network(_) | teloxide::RequestError::RetryAfter(_)
+            ),
+            _ => false,
         }
     }
 }
 
-impl fmt::Display for CustomError {
+impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.message)
+        match self {
+            AppError::Http(e) => write!(f, "request to CardTrader failed: {}", e),
+            AppError::MissingEnv { var, .. } => {
+                write!(f, "{} is not set, add it to your .env file", var)
+            }
+            AppError::Auth(message) => write!(f, "authentication failed: {}", message),
+            AppError::Config(message) => write!(f, "invalid configuration: {}", message),
+            AppError::Json(e) => write!(f, "could not parse JSON: {}", e),
+            AppError::Database(e) => write!(f, "database error: {}", e),
+            AppError::Io(e) => write!(f, "file error: {}", e),
+            AppError::Browser(e) => write!(f, "browser error: {}", e),
+            AppError::Telegram(e) => write!(f, "Telegram error: {}", e),
+            AppError::CardNotFound(card) => write!(f, "card not found: {}", card),
+            AppError::Prompt(e) => write!(f, "prompt error: {}", e),
+            AppError::Task(e) => write!(f, "background task failed: {}", e),
+            AppError::Notification { channel, source } => {
+                write!(f, "{} delivery failed: {}", channel, source)
+            }
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Http(e) => Some(e),
+            AppError::MissingEnv { source, .. } => Some(source),
+            AppError::Json(e) => Some(e),
+            AppError::Database(e) => Some(e),
+            AppError::Io(e) => Some(e),
+            AppError::Browser(e) => Some(e.as_ref()),
+            AppError::Telegram(e) => Some(e),
+            AppError::Prompt(e) => Some(e),
+            AppError::Task(e) => Some(e),
+            AppError::Notification { source, .. } => Some(source.as_ref()),
+            AppError::Auth(_) | AppError::Config(_) | AppError::CardNotFound(_) => None,
+        }
     }
 }
 
-impl std::error::Error for CustomError {}
+impl From<reqwest::Error> for AppError {
+    fn from(e: reqwest::Error) -> Self {
+        AppError::Http(e)
+    }
+}
 
-unsafe impl Send for CustomError {}
-unsafe impl Sync for CustomError {}
+impl From<serde_json::Error> for AppError {
+    fn from(e: serde_json::Error) -> Self {
+        AppError::Json(e)
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(e: rusqlite::Error) -> Self {
+        AppError::Database(e)
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Io(e)
+    }
+}
+
+impl From<teloxide::RequestError> for AppError {
+    fn from(e: teloxide::RequestError) -> Self {
+        AppError::Telegram(e)
+    }
+}
+
+impl From<inquire::InquireError> for AppError {
+    fn from(e: inquire::InquireError) -> Self {
+        AppError::Prompt(e)
+    }
+}
+
+impl From<tokio::task::JoinError> for AppError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        AppError::Task(e)
+    }
+}
@@ -0,0 +1,236 @@
+use crate::error::AppError;
+use crate::pricing::PriceQuote;
+use crate::storage::Storage;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_HISTORY_FILE: &str = "price_history.jsonl";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PriceObservation {
+    pub timestamp: DateTime<Utc>,
+    pub card_key: String,
+    pub price: f64,
+    pub currency: String,
+    pub listings: Option<u32>,
+    pub source: String,
+}
+
+impl PriceObservation {
+    pub fn from_quote(card_key: &str, quote: &PriceQuote, source: &str) -> Self {
+        PriceObservation {
+            timestamp: quote.fetched_at,
+            card_key: card_key.to_string(),
+            price: quote.amount,
+            currency: quote.currency.clone(),
+            listings: quote.listings,
+            source: source.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct PriceStats {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub average: f64,
+    pub first: f64,
+    pub last: f64,
+    pub currency: String,
+}
+
+impl PriceStats {
+    // Espera as observações em ordem cronológica. Só entram as observações na
+    // moeda da mais recente: preços em reais e em euros não se comparam.
+    pub fn from_observations(observations: &[PriceObservation]) -> Option<PriceStats> {
+        let currency = &observations.last()?.currency;
+        let observations: Vec<&PriceObservation> = observations
+            .iter()
+            .filter(|observation| observation.currency == *currency)
+            .collect();
+        let prices = observations.iter().map(|observation| observation.price);
+        Some(PriceStats {
+            count: observations.len(),
+            min: prices.clone().fold(f64::INFINITY, f64::min),
+            max: prices.clone().fold(f64::NEG_INFINITY, f64::max),
+            average: prices.sum::<f64>() / observations.len() as f64,
+            first: observations.first()?.price,
+            last: observations.last()?.price,
+            currency: currency.clone(),
+        })
+    }
+
+    // Variação percentual entre a primeira e a última observação
+    pub fn percent_change(&self) -> Option<f64> {
+        if self.first == 0.0 {
+            return None;
+        }
+        Some((self.last - self.first) / self.first * 100.0)
+    }
+}
+
+// Histórico de preços em JSON Lines: cada observação é uma linha e o arquivo
+// só recebe novas linhas no final.
+pub struct HistoryStore {
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        HistoryStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn append(&self, observations: &[PriceObservation]) -> Result<(), AppError> {
+        if observations.is_empty() {
+            return Ok(());
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let mut writer = BufWriter::new(file);
+        for observation in observations {
+            serde_json::to_writer(&mut writer, observation)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn read_all(&self) -> Result<Vec<PriceObservation>, AppError> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            // Ainda não há histórico; outros erros não podem virar um
+            // histórico vazio, que seria gravado por cima do verdadeiro
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut observations = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            observations.push(serde_json::from_str(&line)?);
+        }
+        Ok(observations)
+    }
+
+    pub fn history_since(
+        &self,
+        card_key: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PriceObservation>, AppError> {
+        let mut observations: Vec<PriceObservation> = self
+            .read_all()?
+            .into_iter()
+            .filter(|observation| observation.card_key == card_key)
+            .filter(|observation| since.is_none_or(|since| observation.timestamp >= since))
+            .collect();
+        observations.sort_by_key(|observation| observation.timestamp);
+        Ok(observations)
+    }
+}
+
+impl Default for HistoryStore {
+    fn default() -> Self {
+        HistoryStore::new(DEFAULT_HISTORY_FILE)
+    }
+}
+
+// Estatísticas das observações dentro da janela (ou de todo o histórico)
+pub fn stats(
+    storage: &dyn Storage,
+    card_key: &str,
+    window: Option<TimeDelta>,
+) -> Result<Option<PriceStats>, AppError> {
+    let since = window.map(|window| Utc::now() - window);
+    let observations = storage.observations(card_key, since)?;
+    Ok(PriceStats::from_observations(&observations))
+}
+
+pub fn percent_change(
+    storage: &dyn Storage,
+    card_key: &str,
+    window: Option<TimeDelta>,
+) -> Result<Option<f64>, AppError> {
+    Ok(stats(storage, card_key, window)?.and_then(|stats| stats.percent_change()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::JsonStorage;
+
+    fn observation(days_ago: i64, price: f64) -> PriceObservation {
+        PriceObservation {
+            timestamp: Utc::now() - TimeDelta::days(days_ago),
+            card_key: "1234".to_string(),
+            price,
+            currency: "EUR".to_string(),
+            listings: Some(3),
+            source: "fixture".to_string(),
+        }
+    }
+
+    #[test]
+    fn stats_cover_min_max_average_and_change() {
+        let observations = [
+            observation(3, 10.0),
+            observation(2, 8.0),
+            observation(1, 12.0),
+        ];
+        let stats = PriceStats::from_observations(&observations).unwrap();
+
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.min, 8.0);
+        assert_eq!(stats.max, 12.0);
+        assert_eq!(stats.average, 10.0);
+        assert_eq!(stats.percent_change(), Some(20.0));
+
+        // Depois de trocar de fonte, só a moeda atual conta
+        let mut in_reais = observation(4, 50.0);
+        in_reais.currency = "BRL".to_string();
+        let observations = [in_reais, observation(2, 8.0), observation(1, 12.0)];
+        let stats = PriceStats::from_observations(&observations).unwrap();
+        assert_eq!((stats.count, stats.first, stats.max), (2, 8.0, 12.0));
+        assert_eq!(stats.currency, "EUR");
+    }
+
+    #[test]
+    fn store_appends_and_filters_by_card_and_window() {
+        let dir = std::env::temp_dir().join(format!("cardtrader_history_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = JsonStorage::new(&dir);
+
+        let mut other_card = observation(1, 99.0);
+        other_card.card_key = "999".to_string();
+        store
+            .append_observations(&[observation(10, 5.0), observation(1, 4.0)])
+            .unwrap();
+        store.append_observations(&[other_card]).unwrap();
+
+        assert_eq!(store.observations("1234", None).unwrap().len(), 2);
+        let recent = stats(&store, "1234", Some(TimeDelta::days(7)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(recent.count, 1);
+        assert_eq!(recent.last, 4.0);
+        assert_eq!(percent_change(&store, "1234", None).unwrap(), Some(-20.0));
+
+        // Um histórico que não pode ser lido é um erro, não um histórico vazio
+        let not_a_dir = dir.join("not_a_dir");
+        std::fs::write(&not_a_dir, "").unwrap();
+        let unreadable = HistoryStore::new(not_a_dir.join(DEFAULT_HISTORY_FILE));
+        assert!(unreadable.history_since("1234", None).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
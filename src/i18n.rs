@@ -0,0 +1,1885 @@
+use crate::settings::Settings;
+use crate::template::{Plain, Template};
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+// Idioma das mensagens da CLI e dos alertas, escolhido com `locale`
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    En,
+    #[serde(rename = "pt-BR")]
+    PtBr,
+    #[serde(rename = "es")]
+    Es,
+    #[serde(rename = "it")]
+    It,
+}
+
+pub const LOCALES: [Locale; 4] = [Locale::En, Locale::PtBr, Locale::Es, Locale::It];
+
+impl Locale {
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::PtBr => "pt-BR",
+            Locale::Es => "es",
+            Locale::It => "it",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Locale::En => 0,
+            Locale::PtBr => 1,
+            Locale::Es => 2,
+            Locale::It => 3,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    // Aceita "pt", "pt-br" e "pt_BR"
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().replace('_', "-").as_str() {
+            "en" | "en-us" | "en-gb" => Ok(Locale::En),
+            "pt" | "pt-br" => Ok(Locale::PtBr),
+            "es" => Ok(Locale::Es),
+            "it" => Ok(Locale::It),
+            other => Err(format!(
+                "unknown language {:?}, use en, pt-BR, es or it",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+thread_local! {
+    // Idioma trocado só nesta thread, por with_locale
+    static THREAD_LOCALE: Cell<Option<Locale>> = const { Cell::new(None) };
+}
+
+// Lido do settings.json na primeira mensagem
+pub fn locale() -> Locale {
+    if let Some(locale) = THREAD_LOCALE.with(Cell::get) {
+        return locale;
+    }
+    *LOCALE.get_or_init(|| {
+        Settings::load()
+            .map(|settings| settings.locale)
+            .unwrap_or_default()
+    })
+}
+
+// Roda `f` com as mensagens em outro idioma, sem mexer no do settings.json
+pub fn with_locale<T>(locale: Locale, f: impl FnOnce() -> T) -> T {
+    let previous = THREAD_LOCALE.with(|current| current.replace(Some(locale)));
+    let result = f();
+    THREAD_LOCALE.with(|current| current.set(previous));
+    result
+}
+
+// Cada mensagem é um template em en, pt-BR, es e it, nessa ordem
+const MESSAGES: &[(&str, [&str; 4])] = &[
+    (
+        "error",
+        [
+            "Error: {{error}}",
+            "Erro: {{error}}",
+            "Error: {{error}}",
+            "Errore: {{error}}",
+        ],
+    ),
+    (
+        "alert.title",
+        [
+            "{{#bold}}Low Price Alert!{{/bold}}{{#if list}} ({{list}}){{/if}}",
+            "{{#bold}}Alerta de Preço Baixo!{{/bold}}{{#if list}} ({{list}}){{/if}}",
+            "{{#bold}}¡Alerta de precio bajo!{{/bold}}{{#if list}} ({{list}}){{/if}}",
+            "{{#bold}}Avviso di prezzo basso!{{/bold}}{{#if list}} ({{list}}){{/if}}",
+        ],
+    ),
+    (
+        "alert.body",
+        [
+            "{{#bold}}{{card}} ({{collector_number}}) [{{set}}]{{/bold}}\nTarget price: {{#bold}}{{target}}{{/bold}}\nCurrent price: {{#bold}}{{price}}{{/bold}}",
+            "{{#bold}}{{card}} ({{collector_number}}) [{{set}}]{{/bold}}\nPreço Desejado: {{#bold}}{{target}}{{/bold}}\nPreço Atual: {{#bold}}{{price}}{{/bold}}",
+            "{{#bold}}{{card}} ({{collector_number}}) [{{set}}]{{/bold}}\nPrecio objetivo: {{#bold}}{{target}}{{/bold}}\nPrecio actual: {{#bold}}{{price}}{{/bold}}",
+            "{{#bold}}{{card}} ({{collector_number}}) [{{set}}]{{/bold}}\nPrezzo desiderato: {{#bold}}{{target}}{{/bold}}\nPrezzo attuale: {{#bold}}{{price}}{{/bold}}",
+        ],
+    ),
+    (
+        "alert.not_sent",
+        [
+            "Alerts not sent by {{channel}}: {{error}}",
+            "Alertas não enviados por {{channel}}: {{error}}",
+            "Alertas no enviadas por {{channel}}: {{error}}",
+            "Avvisi non inviati tramite {{channel}}: {{error}}",
+        ],
+    ),
+    (
+        "check.using",
+        [
+            "Checking prices using {{source}}...",
+            "Verificando preços com {{source}}...",
+            "Comprobando precios con {{source}}...",
+            "Controllo dei prezzi con {{source}}...",
+        ],
+    ),
+    (
+        "check.fetch_failed",
+        [
+            "Failed to fetch price for {{card}}: {{error}}",
+            "Falha ao buscar o preço de {{card}}: {{error}}",
+            "No se pudo obtener el precio de {{card}}: {{error}}",
+            "Impossibile ottenere il prezzo di {{card}}: {{error}}",
+        ],
+    ),
+    (
+        "check.currency_mismatch",
+        [
+            "{{card}}: the price is in {{currency}} but the wishlist prices are in {{expected}}, not compared; remove and add the card again to follow the new currency",
+            "{{card}}: o preço está em {{currency}}, mas os preços da wishlist estão em {{expected}}, sem comparar; remova e adicione a carta de novo para usar a nova moeda",
+            "{{card}}: el precio está en {{currency}}, pero los precios de la wishlist están en {{expected}}, sin comparar; elimina y añade la carta otra vez para usar la nueva moneda",
+            "{{card}}: il prezzo è in {{currency}}, ma i prezzi della wishlist sono in {{expected}}, non confrontato; rimuovi e aggiungi di nuovo la carta per usare la nuova valuta",
+        ],
+    ),
+    (
+        "check.no_listings",
+        [
+            "{{count}} card(s) have no listings",
+            "{{count}} carta(s) sem anúncios",
+            "{{count}} carta(s) sin anuncios",
+            "{{count}} carta/e senza inserzioni",
+        ],
+    ),
+    (
+        "check.failures",
+        [
+            "{{count}} price lookup(s) failed, prices left unchanged",
+            "{{count}} consulta(s) de preço falharam, preços mantidos",
+            "{{count}} consulta(s) de precio fallaron, precios sin cambios",
+            "{{count}} ricerca/e di prezzo non riuscite, prezzi invariati",
+        ],
+    ),
+    (
+        "check.retrying",
+        [
+            "Price check failed, retrying: {{error}}",
+            "A verificação de preços falhou, tentando de novo: {{error}}",
+            "La comprobación de precios falló, reintentando: {{error}}",
+            "Controllo dei prezzi non riuscito, nuovo tentativo: {{error}}",
+        ],
+    ),
+    (
+        "check.summary",
+        [
+            "Checked {{checked}} card(s): {{alerts}} alert(s) ({{suppressed}} already sent), {{no_listings}} without listings, {{failed}} failed",
+            "{{checked}} carta(s) verificada(s): {{alerts}} alerta(s) ({{suppressed}} já enviado(s)), {{no_listings}} sem anúncios, {{failed}} com falha",
+            "{{checked}} carta(s) comprobada(s): {{alerts}} alerta(s) ({{suppressed}} ya enviada(s)), {{no_listings}} sin anuncios, {{failed}} con error",
+            "{{checked}} carta/e controllate: {{alerts}} avviso/i ({{suppressed}} già inviati), {{no_listings}} senza inserzioni, {{failed}} non riuscite",
+        ],
+    ),
+    (
+        "check.queued",
+        [
+            "{{count}} alert(s) queued for the daily digest",
+            "{{count}} alerta(s) guardado(s) para o resumo diário",
+            "{{count}} alerta(s) guardada(s) para el resumen diario",
+            "{{count}} avviso/i in attesa del riepilogo giornaliero",
+        ],
+    ),
+    (
+        "sync.summary",
+        [
+            "Synced {{expansions}} expansion(s): {{added}} blueprint(s) added, {{changed}} changed, {{removed}} removed",
+            "{{expansions}} expansão(ões) sincronizada(s): {{added}} blueprint(s) adicionado(s), {{changed}} alterado(s), {{removed}} removido(s)",
+            "{{expansions}} expansión(es) sincronizada(s): {{added}} blueprint(s) añadido(s), {{changed}} modificado(s), {{removed}} eliminado(s)",
+            "{{expansions}} espansione/i sincronizzate: {{added}} blueprint aggiunti, {{changed}} modificati, {{removed}} rimossi",
+        ],
+    ),
+    (
+        "sync.failed",
+        [
+            "Not synced, will be retried next time: {{failure}}",
+            "Não sincronizada, será tentada de novo na próxima vez: {{failure}}",
+            "No sincronizada, se reintentará la próxima vez: {{failure}}",
+            "Non sincronizzata, verrà ritentata la prossima volta: {{failure}}",
+        ],
+    ),
+    (
+        "preferences.default",
+        [
+            "Default preferences: {{preferences}}",
+            "Preferências padrão: {{preferences}}",
+            "Preferencias predeterminadas: {{preferences}}",
+            "Preferenze predefinite: {{preferences}}",
+        ],
+    ),
+    (
+        "channels.telegram_only",
+        [
+            "Alerts go to Telegram",
+            "Os alertas vão para o Telegram",
+            "Las alertas van a Telegram",
+            "Gli avvisi vanno su Telegram",
+        ],
+    ),
+    (
+        "alert_rules.repeat",
+        [
+            "Alerts already sent are repeated after {{cooldown}} hour(s), or sooner if the price drops {{step}}% more",
+            "Alertas já enviados se repetem depois de {{cooldown}} hora(s), ou antes se o preço cair mais {{step}}%",
+            "Las alertas ya enviadas se repiten después de {{cooldown}} hora(s), o antes si el precio baja otro {{step}}%",
+            "Gli avvisi già inviati si ripetono dopo {{cooldown}} ora/e, o prima se il prezzo scende di un altro {{step}}%",
+        ],
+    ),
+    (
+        "alert_rules.digest",
+        [
+            "Alerts are batched into a daily digest at {{hour}}:00",
+            "Os alertas são juntados em um resumo diário às {{hour}}h",
+            "Las alertas se agrupan en un resumen diario a las {{hour}}:00",
+            "Gli avvisi sono raccolti in un riepilogo giornaliero alle {{hour}}:00",
+        ],
+    ),
+    (
+        "alert_rules.no_digest",
+        [
+            "Alerts are sent right away",
+            "Os alertas são enviados na hora",
+            "Las alertas se envían al momento",
+            "Gli avvisi sono inviati subito",
+        ],
+    ),
+    (
+        "game.default_set",
+        [
+            "Default game set to {{game}}",
+            "Jogo padrão definido como {{game}}",
+            "Juego predeterminado: {{game}}",
+            "Gioco predefinito impostato su {{game}}",
+        ],
+    ),
+    (
+        "locale.set",
+        [
+            "Language set to {{locale}}",
+            "Idioma definido como {{locale}}",
+            "Idioma establecido en {{locale}}",
+            "Lingua impostata su {{locale}}",
+        ],
+    ),
+    (
+        "templates.title",
+        [
+            "Title template:",
+            "Template do título:",
+            "Plantilla del título:",
+            "Modello del titolo:",
+        ],
+    ),
+    (
+        "templates.body",
+        [
+            "Alert template:",
+            "Template do alerta:",
+            "Plantilla de la alerta:",
+            "Modello dell'avviso:",
+        ],
+    ),
+    (
+        "templates.preview",
+        [
+            "Preview:",
+            "Prévia:",
+            "Vista previa:",
+            "Anteprima:",
+        ],
+    ),
+    (
+        "item.describe",
+        [
+            "{{quantity}}{{card}} ({{collector_number}}) - {{set}}{{version}}: target {{target}}, last seen {{last_price}}{{preferences}}",
+            "{{quantity}}{{card}} ({{collector_number}}) - {{set}}{{version}}: desejado {{target}}, último visto {{last_price}}{{preferences}}",
+            "{{quantity}}{{card}} ({{collector_number}}) - {{set}}{{version}}: objetivo {{target}}, último visto {{last_price}}{{preferences}}",
+            "{{quantity}}{{card}} ({{collector_number}}) - {{set}}{{version}}: obiettivo {{target}}, ultimo visto {{last_price}}{{preferences}}",
+        ],
+    ),
+    (
+        "item.added",
+        [
+            "Added {{item}}",
+            "Adicionado {{item}}",
+            "Añadido {{item}}",
+            "Aggiunto {{item}}",
+        ],
+    ),
+    (
+        "item.updated",
+        [
+            "Updated {{item}}",
+            "Atualizado {{item}}",
+            "Actualizado {{item}}",
+            "Aggiornato {{item}}",
+        ],
+    ),
+    (
+        "item.removed",
+        [
+            "Removed {{item}}",
+            "Removido {{item}}",
+            "Eliminado {{item}}",
+            "Rimosso {{item}}",
+        ],
+    ),
+    (
+        "item.no_match",
+        [
+            "No wishlist item matches{{#if card}} {{card}}{{/if}}",
+            "Nenhum item da wishlist corresponde{{#if card}} a {{card}}{{/if}}",
+            "Ningún elemento de la wishlist coincide{{#if card}} con {{card}}{{/if}}",
+            "Nessun elemento della wishlist corrisponde{{#if card}} a {{card}}{{/if}}",
+        ],
+    ),
+    (
+        "edit.nothing",
+        [
+            "Nothing to change, pass --target-price or --quantity",
+            "Nada para mudar, passe --target-price ou --quantity",
+            "Nada que cambiar, usa --target-price o --quantity",
+            "Niente da modificare, usa --target-price o --quantity",
+        ],
+    ),
+    (
+        "item.invalid_quantity",
+        [
+            "The quantity must be at least 1",
+            "A quantidade deve ser pelo menos 1",
+            "La cantidad debe ser al menos 1",
+            "La quantità deve essere almeno 1",
+        ],
+    ),
+    (
+        "item.invalid_target",
+        [
+            "The target price must be 0 or more, got {{price}}",
+            "O preço desejado deve ser 0 ou mais, recebido {{price}}",
+            "El precio deseado debe ser 0 o más, recibido {{price}}",
+            "Il prezzo desiderato deve essere 0 o più, ricevuto {{price}}",
+        ],
+    ),
+    (
+        "edit.ambiguous",
+        [
+            "{{count}} wishlist items match, narrow it down with --expansion or --blueprint-id:",
+            "{{count}} itens da wishlist correspondem, restrinja com --expansion ou --blueprint-id:",
+            "{{count}} elementos de la wishlist coinciden, acota con --expansion o --blueprint-id:",
+            "{{count}} elementi della wishlist corrispondono, restringi con --expansion o --blueprint-id:",
+        ],
+    ),
+    (
+        "dedup.merged",
+        [
+            "Merged {{count}} repeated item(s)",
+            "{{count}} item(ns) repetido(s) unido(s)",
+            "{{count}} elemento(s) repetido(s) unido(s)",
+            "{{count}} elemento/i ripetuti uniti",
+        ],
+    ),
+    (
+        "bot.running",
+        [
+            "Telegram bot running for the {{list}} wishlist",
+            "Bot do Telegram rodando para a wishlist {{list}}",
+            "Bot de Telegram en marcha para la wishlist {{list}}",
+            "Bot di Telegram in esecuzione per la wishlist {{list}}",
+        ],
+    ),
+    (
+        "import_json.nothing",
+        [
+            "CARDTRADER_STORAGE is json, there is nothing to import",
+            "CARDTRADER_STORAGE é json, não há nada para importar",
+            "CARDTRADER_STORAGE es json, no hay nada que importar",
+            "CARDTRADER_STORAGE è json, non c'è niente da importare",
+        ],
+    ),
+    (
+        "import_json.done",
+        [
+            "Imported {{count}} wishlist item(s) into {{storage}}",
+            "{{count}} item(ns) da wishlist importado(s) para {{storage}}",
+            "{{count}} elemento(s) de la wishlist importado(s) en {{storage}}",
+            "{{count}} elemento/i della wishlist importati in {{storage}}",
+        ],
+    ),
+    (
+        "wishlist.cards",
+        [
+            "{{count}} card(s)",
+            "{{count}} carta(s)",
+            "{{count}} carta(s)",
+            "{{count}} carta/e",
+        ],
+    ),
+    (
+        "wishlist.created",
+        [
+            "Created wishlist {{name}}",
+            "Wishlist {{name}} criada",
+            "Wishlist {{name}} creada",
+            "Wishlist {{name}} creata",
+        ],
+    ),
+    (
+        "wishlist.renamed",
+        [
+            "Renamed wishlist {{from}} to {{to}}",
+            "Wishlist {{from}} renomeada para {{to}}",
+            "Wishlist {{from}} renombrada a {{to}}",
+            "Wishlist {{from}} rinominata in {{to}}",
+        ],
+    ),
+    (
+        "wishlist.deleted",
+        [
+            "Deleted wishlist {{name}}",
+            "Wishlist {{name}} apagada",
+            "Wishlist {{name}} eliminada",
+            "Wishlist {{name}} eliminata",
+        ],
+    ),
+    (
+        "wishlist.already_in",
+        [
+            "Already in {{list}}: {{item}}",
+            "Já está em {{list}}: {{item}}",
+            "Ya está en {{list}}: {{item}}",
+            "Già in {{list}}: {{item}}",
+        ],
+    ),
+    (
+        "wishlist.copied",
+        [
+            "Copied {{count}} card(s) from {{from}} to {{to}}",
+            "{{count}} carta(s) copiada(s) de {{from}} para {{to}}",
+            "{{count}} carta(s) copiada(s) de {{from}} a {{to}}",
+            "{{count}} carta/e copiate da {{from}} a {{to}}",
+        ],
+    ),
+    (
+        "alerts.off",
+        [
+            "alerts off",
+            "alertas desligados",
+            "alertas desactivadas",
+            "avvisi disattivati",
+        ],
+    ),
+    (
+        "alerts.on",
+        [
+            "alerts on",
+            "alertas ligados",
+            "alertas activadas",
+            "avvisi attivi",
+        ],
+    ),
+    (
+        "alerts.to_chat",
+        [
+            "alerts to chat {{chat_id}}",
+            "alertas para o chat {{chat_id}}",
+            "alertas al chat {{chat_id}}",
+            "avvisi alla chat {{chat_id}}",
+        ],
+    ),
+    (
+        "alerts.to",
+        [
+            "alerts to {{channels}}",
+            "alertas para {{channels}}",
+            "alertas a {{channels}}",
+            "avvisi a {{channels}}",
+        ],
+    ),
+    (
+        "bot_users.owner",
+        [
+            "owner (TELEGRAM_CHAT_ID)",
+            "dono (TELEGRAM_CHAT_ID)",
+            "propietario (TELEGRAM_CHAT_ID)",
+            "proprietario (TELEGRAM_CHAT_ID)",
+        ],
+    ),
+    (
+        "bot_users.added",
+        [
+            "{{name}} ({{chat_id}}) can use the bot{{#if admin}} as an admin{{/if}}, wishlist {{list}}",
+            "{{name}} ({{chat_id}}) pode usar o bot{{#if admin}} como admin{{/if}}, wishlist {{list}}",
+            "{{name}} ({{chat_id}}) puede usar el bot{{#if admin}} como admin{{/if}}, wishlist {{list}}",
+            "{{name}} ({{chat_id}}) può usare il bot{{#if admin}} come admin{{/if}}, wishlist {{list}}",
+        ],
+    ),
+    (
+        "bot_users.removed",
+        [
+            "{{name}} ({{chat_id}}) can no longer use the bot, the {{list}} wishlist was kept",
+            "{{name}} ({{chat_id}}) não pode mais usar o bot, a wishlist {{list}} foi mantida",
+            "{{name}} ({{chat_id}}) ya no puede usar el bot, la wishlist {{list}} se conserva",
+            "{{name}} ({{chat_id}}) non può più usare il bot, la wishlist {{list}} è stata mantenuta",
+        ],
+    ),
+    (
+        "bot_users.not_allowed",
+        [
+            "{{chat_id}} was not allowed to use the bot",
+            "{{chat_id}} não tinha acesso ao bot",
+            "{{chat_id}} no tenía acceso al bot",
+            "{{chat_id}} non aveva accesso al bot",
+        ],
+    ),
+    (
+        "add.not_found",
+        [
+            "No card named \"{{card}}\" was found",
+            "Nenhuma carta chamada \"{{card}}\" foi encontrada",
+            "No se encontró ninguna carta llamada \"{{card}}\"",
+            "Nessuna carta chiamata \"{{card}}\" trovata",
+        ],
+    ),
+    (
+        "add.did_you_mean",
+        [
+            "Did you mean:",
+            "Você quis dizer:",
+            "Quizás quisiste decir:",
+            "Forse intendevi:",
+        ],
+    ),
+    (
+        "add.ambiguous",
+        [
+            "\"{{card}}\" matches {{count}} versions, narrow it down with --expansion or --collector-number:",
+            "\"{{card}}\" tem {{count}} versões, restrinja com --expansion ou --collector-number:",
+            "\"{{card}}\" tiene {{count}} versiones, acota con --expansion o --collector-number:",
+            "\"{{card}}\" ha {{count}} versioni, restringi con --expansion o --collector-number:",
+        ],
+    ),
+    (
+        "add.no_listings",
+        [
+            "{{card}} has no listings matching the preferences, pass --target-price",
+            "{{card}} não tem anúncios que atendam às preferências, passe --target-price",
+            "{{card}} no tiene anuncios que cumplan las preferencias, usa --target-price",
+            "{{card}} non ha inserzioni che rispettano le preferenze, usa --target-price",
+        ],
+    ),
+    (
+        "add.duplicate",
+        [
+            "{{item}} is already in the wishlist, use `edit` to change it",
+            "{{item}} já está na wishlist, use `edit` para mudá-lo",
+            "{{item}} ya está en la wishlist, usa `edit` para cambiarlo",
+            "{{item}} è già nella wishlist, usa `edit` per modificarlo",
+        ],
+    ),
+    (
+        "history.stats",
+        [
+            "  {{count}} observation(s): min {{min}}, max {{max}}, avg {{average}}, change {{change}}",
+            "  {{count}} observação(ões): mín {{min}}, máx {{max}}, média {{average}}, variação {{change}}",
+            "  {{count}} observación(es): mín {{min}}, máx {{max}}, media {{average}}, variación {{change}}",
+            "  {{count}} osservazione/i: min {{min}}, max {{max}}, media {{average}}, variazione {{change}}",
+        ],
+    ),
+    (
+        "history.empty",
+        [
+            "  no price history yet",
+            "  ainda sem histórico de preços",
+            "  todavía sin historial de precios",
+            "  ancora nessuno storico dei prezzi",
+        ],
+    ),
+    (
+        "import.ambiguous",
+        [
+            "Line {{line}}: \"{{card}}\" matches {{count}} versions, add the set code and collector number:",
+            "Linha {{line}}: \"{{card}}\" tem {{count}} versões, adicione o código da expansão e o número:",
+            "Línea {{line}}: \"{{card}}\" tiene {{count}} versiones, añade el código de la expansión y el número:",
+            "Riga {{line}}: \"{{card}}\" ha {{count}} versioni, aggiungi il codice dell'espansione e il numero:",
+        ],
+    ),
+    (
+        "import.duplicate",
+        [
+            "Already in the wishlist: {{item}}",
+            "Já está na wishlist: {{item}}",
+            "Ya está en la wishlist: {{item}}",
+            "Già nella wishlist: {{item}}",
+        ],
+    ),
+    (
+        "import.not_found",
+        [
+            "Line {{line}}: no card named \"{{card}}\"",
+            "Linha {{line}}: nenhuma carta chamada \"{{card}}\"",
+            "Línea {{line}}: ninguna carta llamada \"{{card}}\"",
+            "Riga {{line}}: nessuna carta chiamata \"{{card}}\"",
+        ],
+    ),
+    (
+        "import.no_price",
+        [
+            "Line {{line}}: no current price for \"{{card}}\", add it with --target-price",
+            "Linha {{line}}: sem preço atual para \"{{card}}\", adicione com --target-price",
+            "Línea {{line}}: sin precio actual para \"{{card}}\", añádela con --target-price",
+            "Riga {{line}}: nessun prezzo attuale per \"{{card}}\", aggiungila con --target-price",
+        ],
+    ),
+    (
+        "import.summary",
+        [
+            "Imported {{added}} card(s); {{ambiguous}} ambiguous, {{not_found}} not found, {{no_price}} without price",
+            "{{added}} carta(s) importada(s); {{ambiguous}} ambígua(s), {{not_found}} não encontrada(s), {{no_price}} sem preço",
+            "{{added}} carta(s) importada(s); {{ambiguous}} ambigua(s), {{not_found}} no encontrada(s), {{no_price}} sin precio",
+            "{{added}} carta/e importate; {{ambiguous}} ambigue, {{not_found}} non trovate, {{no_price}} senza prezzo",
+        ],
+    ),
+    (
+        "check.finished",
+        [
+            "Finished checking prices",
+            "Verificação de preços concluída",
+            "Comprobación de precios terminada",
+            "Controllo dei prezzi completato",
+        ],
+    ),
+    (
+        "menu.search",
+        [
+            "Search a card:",
+            "Buscar uma carta:",
+            "Buscar una carta:",
+            "Cerca una carta:",
+        ],
+    ),
+    (
+        "menu.search_help",
+        [
+            "Name, or set code + collector number like \"MH3 123\"",
+            "Nome, ou código da expansão + número de coleção como \"MH3 123\"",
+            "Nombre, o código de la expansión + número de colección como \"MH3 123\"",
+            "Nome, o codice dell'espansione + numero di collezione come \"MH3 123\"",
+        ],
+    ),
+    (
+        "menu.no_match",
+        [
+            "No cards match \"{{query}}\", try again.",
+            "Nenhuma carta corresponde a \"{{query}}\", tente de novo.",
+            "Ninguna carta coincide con \"{{query}}\", inténtalo de nuevo.",
+            "Nessuna carta corrisponde a \"{{query}}\", riprova.",
+        ],
+    ),
+    (
+        "menu.select_name",
+        [
+            "Select a card name:",
+            "Selecione o nome da carta:",
+            "Selecciona el nombre de la carta:",
+            "Seleziona il nome della carta:",
+        ],
+    ),
+    (
+        "menu.select_version",
+        [
+            "Select a card version:",
+            "Selecione a versão da carta:",
+            "Selecciona la versión de la carta:",
+            "Seleziona la versione della carta:",
+        ],
+    ),
+    (
+        "menu.all_versions",
+        [
+            "Add all versions",
+            "Adicionar todas as versões",
+            "Añadir todas las versiones",
+            "Aggiungi tutte le versioni",
+        ],
+    ),
+    (
+        "menu.adding_all",
+        [
+            "You selected to add all versions of {{card}}:",
+            "Adicionando todas as versões de {{card}}:",
+            "Añadiendo todas las versiones de {{card}}:",
+            "Aggiunta di tutte le versioni di {{card}}:",
+        ],
+    ),
+    (
+        "menu.version_skipped",
+        [
+            "{{card}} ({{set}}) has no matching listings, skipped",
+            "{{card}} ({{set}}) não tem anúncios que atendam, ignorada",
+            "{{card}} ({{set}}) no tiene anuncios que coincidan, omitida",
+            "{{card}} ({{set}}) non ha inserzioni corrispondenti, saltata",
+        ],
+    ),
+    (
+        "menu.all_added",
+        [
+            "Finished adding all versions to wishlist",
+            "Todas as versões foram adicionadas à wishlist",
+            "Se añadieron todas las versiones a la wishlist",
+            "Tutte le versioni sono state aggiunte alla wishlist",
+        ],
+    ),
+    (
+        "menu.version_failed",
+        [
+            "Failed to add a card version: {{error}}",
+            "Falha ao adicionar uma versão da carta: {{error}}",
+            "No se pudo añadir una versión de la carta: {{error}}",
+            "Impossibile aggiungere una versione della carta: {{error}}",
+        ],
+    ),
+    (
+        "menu.versions_already_in",
+        [
+            "{{count}} version(s) were already in the wishlist",
+            "{{count}} versão(ões) já estava(m) na wishlist",
+            "{{count}} versión(es) ya estaba(n) en la wishlist",
+            "{{count}} versione/i erano già nella wishlist",
+        ],
+    ),
+    (
+        "menu.image",
+        [
+            "Image: {{url}}",
+            "Imagem: {{url}}",
+            "Imagen: {{url}}",
+            "Immagine: {{url}}",
+        ],
+    ),
+    (
+        "menu.quantity",
+        [
+            "How many copies?",
+            "Quantas cópias?",
+            "¿Cuántas copias?",
+            "Quante copie?",
+        ],
+    ),
+    (
+        "menu.use_default_preferences",
+        [
+            "Use the default listing preferences ({{preferences}})?",
+            "Usar as preferências de anúncio padrão ({{preferences}})?",
+            "¿Usar las preferencias de anuncio predeterminadas ({{preferences}})?",
+            "Usare le preferenze predefinite delle inserzioni ({{preferences}})?",
+        ],
+    ),
+    (
+        "menu.no_listings_target",
+        [
+            "No listings match. Desired price:",
+            "Nenhum anúncio corresponde. Preço desejado:",
+            "Ningún anuncio coincide. Precio objetivo:",
+            "Nessuna inserzione corrisponde. Prezzo desiderato:",
+        ],
+    ),
+    (
+        "menu.already_in",
+        [
+            "This card is already in the wishlist.",
+            "Esta carta já está na wishlist.",
+            "Esta carta ya está en la wishlist.",
+            "Questa carta è già nella wishlist.",
+        ],
+    ),
+    (
+        "menu.version_not_selected",
+        [
+            "Failed to select a card version.",
+            "Falha ao selecionar a versão da carta.",
+            "No se pudo seleccionar la versión de la carta.",
+            "Impossibile selezionare la versione della carta.",
+        ],
+    ),
+    (
+        "menu.no_card",
+        [
+            "No card selected.",
+            "Nenhuma carta selecionada.",
+            "Ninguna carta seleccionada.",
+            "Nessuna carta selezionata.",
+        ],
+    ),
+    (
+        "menu.name_not_selected",
+        [
+            "Failed to select a card name.",
+            "Falha ao selecionar o nome da carta.",
+            "No se pudo seleccionar el nombre de la carta.",
+            "Impossibile selezionare il nome della carta.",
+        ],
+    ),
+    (
+        "menu.decklist_file",
+        [
+            "Decklist file:",
+            "Arquivo da lista:",
+            "Archivo de la lista:",
+            "File della lista:",
+        ],
+    ),
+    (
+        "menu.decklist_help",
+        [
+            "Plain text, MTG Arena, MTGO .dek or Moxfield/Archidekt CSV",
+            "Texto puro, MTG Arena, MTGO .dek ou CSV do Moxfield/Archidekt",
+            "Texto plano, MTG Arena, MTGO .dek o CSV de Moxfield/Archidekt",
+            "Testo semplice, MTG Arena, MTGO .dek o CSV di Moxfield/Archidekt",
+        ],
+    ),
+    (
+        "menu.discount",
+        [
+            "Target price, % below the current price:",
+            "Preço desejado, % abaixo do preço atual:",
+            "Precio objetivo, % por debajo del precio actual:",
+            "Prezzo desiderato, % sotto il prezzo attuale:",
+        ],
+    ),
+    (
+        "menu.target_price",
+        [
+            "Desired price:",
+            "Preço desejado:",
+            "Precio objetivo:",
+            "Prezzo desiderato:",
+        ],
+    ),
+    (
+        "menu.change_preferences",
+        [
+            "Change the listing preferences?",
+            "Mudar as preferências de anúncio?",
+            "¿Cambiar las preferencias de anuncio?",
+            "Modificare le preferenze delle inserzioni?",
+        ],
+    ),
+    (
+        "menu.wishlist",
+        [
+            "Wishlist:",
+            "Wishlist:",
+            "Wishlist:",
+            "Wishlist:",
+        ],
+    ),
+    (
+        "menu.show_wishlist",
+        [
+            "Show wishlist",
+            "Mostrar wishlist",
+            "Mostrar wishlist",
+            "Mostra wishlist",
+        ],
+    ),
+    (
+        "menu.edit_card",
+        [
+            "Edit a card",
+            "Editar uma carta",
+            "Editar una carta",
+            "Modifica una carta",
+        ],
+    ),
+    (
+        "menu.remove_cards",
+        [
+            "Remove cards",
+            "Remover cartas",
+            "Eliminar cartas",
+            "Rimuovi carte",
+        ],
+    ),
+    (
+        "menu.merge_cards",
+        [
+            "Merge repeated cards",
+            "Juntar cartas repetidas",
+            "Unir cartas repetidas",
+            "Unisci carte ripetute",
+        ],
+    ),
+    (
+        "menu.back",
+        [
+            "Back",
+            "Voltar",
+            "Volver",
+            "Indietro",
+        ],
+    ),
+    (
+        "menu.empty",
+        [
+            "The wishlist is empty.",
+            "A wishlist está vazia.",
+            "La wishlist está vacía.",
+            "La wishlist è vuota.",
+        ],
+    ),
+    (
+        "menu.sort_by",
+        [
+            "Sort by:",
+            "Ordenar por:",
+            "Ordenar por:",
+            "Ordina per:",
+        ],
+    ),
+    (
+        "menu.sort_name",
+        [
+            "Name",
+            "Nome",
+            "Nombre",
+            "Nome",
+        ],
+    ),
+    (
+        "menu.sort_price",
+        [
+            "Price",
+            "Preço",
+            "Precio",
+            "Prezzo",
+        ],
+    ),
+    (
+        "menu.sort_gap",
+        [
+            "Gap to target",
+            "Distância do preço desejado",
+            "Distancia al precio objetivo",
+            "Distanza dal prezzo desiderato",
+        ],
+    ),
+    (
+        "menu.which_card",
+        [
+            "Which card?",
+            "Qual carta?",
+            "¿Qué carta?",
+            "Quale carta?",
+        ],
+    ),
+    (
+        "menu.which_cards",
+        [
+            "Which cards?",
+            "Quais cartas?",
+            "¿Qué cartas?",
+            "Quali carte?",
+        ],
+    ),
+    (
+        "menu.removed",
+        [
+            "Removed {{count}} card(s)",
+            "{{count}} carta(s) removida(s)",
+            "{{count}} carta(s) eliminada(s)",
+            "{{count}} carta/e rimosse",
+        ],
+    ),
+    (
+        "menu.send_alerts",
+        [
+            "Send price alerts for this wishlist?",
+            "Enviar alertas de preço desta wishlist?",
+            "¿Enviar alertas de precio de esta wishlist?",
+            "Inviare avvisi di prezzo per questa wishlist?",
+        ],
+    ),
+    (
+        "menu.chat_id",
+        [
+            "Telegram chat id:",
+            "Id do chat do Telegram:",
+            "Id del chat de Telegram:",
+            "Id della chat Telegram:",
+        ],
+    ),
+    (
+        "menu.chat_id_help",
+        [
+            "Empty sends to the default chat",
+            "Vazio envia para o chat padrão",
+            "Vacío envía al chat predeterminado",
+            "Vuoto invia alla chat predefinita",
+        ],
+    ),
+    (
+        "menu.channels",
+        [
+            "Channels:",
+            "Canais:",
+            "Canales:",
+            "Canali:",
+        ],
+    ),
+    (
+        "menu.channels_help",
+        [
+            "Separated by spaces: telegram, discord:URL, slack:URL, email:ADDRESS, ntfy:TOPIC, gotify:URL?token=TOKEN, webhook:URL. Empty uses the defaults",
+            "Separados por espaço: telegram, discord:URL, slack:URL, email:ENDEREÇO, ntfy:TÓPICO, gotify:URL?token=TOKEN, webhook:URL. Vazio usa os padrões",
+            "Separados por espacios: telegram, discord:URL, slack:URL, email:DIRECCIÓN, ntfy:TEMA, gotify:URL?token=TOKEN, webhook:URL. Vacío usa los predeterminados",
+            "Separati da spazi: telegram, discord:URL, slack:URL, email:INDIRIZZO, ntfy:ARGOMENTO, gotify:URL?token=TOKEN, webhook:URL. Vuoto usa quelli predefiniti",
+        ],
+    ),
+    (
+        "menu.wishlists",
+        [
+            "Wishlists (current: {{list}}):",
+            "Wishlists (atual: {{list}}):",
+            "Wishlists (actual: {{list}}):",
+            "Wishlist (attuale: {{list}}):",
+        ],
+    ),
+    (
+        "menu.switch_wishlist",
+        [
+            "Switch wishlist",
+            "Trocar de wishlist",
+            "Cambiar de wishlist",
+            "Cambia wishlist",
+        ],
+    ),
+    (
+        "menu.create_wishlist",
+        [
+            "Create wishlist",
+            "Criar wishlist",
+            "Crear wishlist",
+            "Crea wishlist",
+        ],
+    ),
+    (
+        "menu.rename_wishlist",
+        [
+            "Rename wishlist",
+            "Renomear wishlist",
+            "Renombrar wishlist",
+            "Rinomina wishlist",
+        ],
+    ),
+    (
+        "menu.delete_wishlist",
+        [
+            "Delete wishlist",
+            "Apagar wishlist",
+            "Eliminar wishlist",
+            "Elimina wishlist",
+        ],
+    ),
+    (
+        "menu.copy_cards",
+        [
+            "Copy cards",
+            "Copiar cartas",
+            "Copiar cartas",
+            "Copia carte",
+        ],
+    ),
+    (
+        "menu.alert_settings",
+        [
+            "Alert settings",
+            "Configurar alertas",
+            "Configurar alertas",
+            "Impostazioni degli avvisi",
+        ],
+    ),
+    (
+        "menu.which_wishlist",
+        [
+            "Which wishlist?",
+            "Qual wishlist?",
+            "¿Qué wishlist?",
+            "Quale wishlist?",
+        ],
+    ),
+    (
+        "menu.name",
+        [
+            "Name:",
+            "Nome:",
+            "Nombre:",
+            "Nome:",
+        ],
+    ),
+    (
+        "menu.new_name",
+        [
+            "New name:",
+            "Novo nome:",
+            "Nuevo nombre:",
+            "Nuovo nome:",
+        ],
+    ),
+    (
+        "menu.confirm_delete",
+        [
+            "Delete {{list}} and all its cards?",
+            "Apagar {{list}} e todas as suas cartas?",
+            "¿Eliminar {{list}} y todas sus cartas?",
+            "Eliminare {{list}} e tutte le sue carte?",
+        ],
+    ),
+    (
+        "menu.copy_from",
+        [
+            "Copy from:",
+            "Copiar de:",
+            "Copiar de:",
+            "Copia da:",
+        ],
+    ),
+    (
+        "menu.copy_to",
+        [
+            "Copy to:",
+            "Copiar para:",
+            "Copiar a:",
+            "Copia in:",
+        ],
+    ),
+    (
+        "menu.copy_only",
+        [
+            "Only cards named (empty copies all):",
+            "Só as cartas chamadas (vazio copia todas):",
+            "Solo las cartas llamadas (vacío copia todas):",
+            "Solo le carte chiamate (vuoto copia tutte):",
+        ],
+    ),
+    (
+        "menu.copied",
+        [
+            "Copied {{count}} card(s), {{duplicates}} already in {{list}}",
+            "{{count}} carta(s) copiada(s), {{duplicates}} já em {{list}}",
+            "{{count}} carta(s) copiada(s), {{duplicates}} ya en {{list}}",
+            "{{count}} carta/e copiate, {{duplicates}} già in {{list}}",
+        ],
+    ),
+    (
+        "menu.condition",
+        [
+            "Worst accepted condition:",
+            "Pior condição aceita:",
+            "Peor condición aceptada:",
+            "Peggior condizione accettata:",
+        ],
+    ),
+    (
+        "menu.any_condition",
+        [
+            "Any",
+            "Qualquer",
+            "Cualquiera",
+            "Qualsiasi",
+        ],
+    ),
+    (
+        "menu.languages",
+        [
+            "Accepted languages:",
+            "Idiomas aceitos:",
+            "Idiomas aceptados:",
+            "Lingue accettate:",
+        ],
+    ),
+    (
+        "menu.languages_help",
+        [
+            "Comma separated, e.g. en,it; empty accepts any",
+            "Separados por vírgula, ex. en,it; vazio aceita qualquer um",
+            "Separados por comas, p. ej. en,it; vacío acepta cualquiera",
+            "Separate da virgole, es. en,it; vuoto accetta qualsiasi",
+        ],
+    ),
+    (
+        "menu.foil",
+        [
+            "Foil:",
+            "Foil:",
+            "Foil:",
+            "Foil:",
+        ],
+    ),
+    (
+        "menu.foil_any",
+        [
+            "Any",
+            "Qualquer",
+            "Cualquiera",
+            "Qualsiasi",
+        ],
+    ),
+    (
+        "menu.foil_only",
+        [
+            "Foil only",
+            "Só foil",
+            "Solo foil",
+            "Solo foil",
+        ],
+    ),
+    (
+        "menu.non_foil_only",
+        [
+            "Non-foil only",
+            "Só não foil",
+            "Solo no foil",
+            "Solo non foil",
+        ],
+    ),
+    (
+        "menu.zero_only",
+        [
+            "Only CardTrader Zero sellers?",
+            "Só vendedores CardTrader Zero?",
+            "¿Solo vendedores de CardTrader Zero?",
+            "Solo venditori CardTrader Zero?",
+        ],
+    ),
+    (
+        "menu.which_game",
+        [
+            "Which game?",
+            "Qual jogo?",
+            "¿Qué juego?",
+            "Quale gioco?",
+        ],
+    ),
+    (
+        "menu.default_game",
+        [
+            "Use it as your default game?",
+            "Usar como jogo padrão?",
+            "¿Usarlo como juego predeterminado?",
+            "Usarlo come gioco predefinito?",
+        ],
+    ),
+    (
+        "menu.loading",
+        [
+            "Loading the program, please wait a moment...",
+            "Carregando o programa, aguarde um momento...",
+            "Cargando el programa, espera un momento...",
+            "Caricamento del programma, attendi un momento...",
+        ],
+    ),
+    (
+        "menu.downloading",
+        [
+            "No blueprints saved yet. Downloading them now...",
+            "Ainda não há blueprints salvos. Baixando agora...",
+            "Todavía no hay blueprints guardados. Descargándolos ahora...",
+            "Nessun blueprint salvato. Download in corso...",
+        ],
+    ),
+    (
+        "menu.welcome",
+        [
+            "Hello, {{user}}! Welcome to CardTrader!",
+            "Olá, {{user}}! Bem-vindo ao CardTrader!",
+            "¡Hola, {{user}}! ¡Bienvenido a CardTrader!",
+            "Ciao, {{user}}! Benvenuto su CardTrader!",
+        ],
+    ),
+    (
+        "menu.adding_from",
+        [
+            "Adding cards from {{game}}",
+            "Adicionando cartas de {{game}}",
+            "Añadiendo cartas de {{game}}",
+            "Aggiunta di carte da {{game}}",
+        ],
+    ),
+    (
+        "menu.bot_stopped",
+        [
+            "The Telegram bot stopped: {{error}}",
+            "O bot do Telegram parou: {{error}}",
+            "El bot de Telegram se detuvo: {{error}}",
+            "Il bot di Telegram si è fermato: {{error}}",
+        ],
+    ),
+    (
+        "menu.add_card",
+        [
+            "Add card",
+            "Adicionar carta",
+            "Añadir carta",
+            "Aggiungi carta",
+        ],
+    ),
+    (
+        "menu.import_decklist",
+        [
+            "Import decklist",
+            "Importar lista",
+            "Importar lista",
+            "Importa lista",
+        ],
+    ),
+    (
+        "menu.manage_wishlist",
+        [
+            "Manage wishlist",
+            "Gerenciar wishlist",
+            "Gestionar wishlist",
+            "Gestisci wishlist",
+        ],
+    ),
+    (
+        "menu.wishlists_entry",
+        [
+            "Wishlists",
+            "Wishlists",
+            "Wishlists",
+            "Wishlist",
+        ],
+    ),
+    (
+        "menu.change_game",
+        [
+            "Change game",
+            "Trocar de jogo",
+            "Cambiar de juego",
+            "Cambia gioco",
+        ],
+    ),
+    (
+        "menu.default_preferences",
+        [
+            "Default preferences",
+            "Preferências padrão",
+            "Preferencias predeterminadas",
+            "Preferenze predefinite",
+        ],
+    ),
+    (
+        "menu.check_prices",
+        [
+            "Check prices",
+            "Verificar preços",
+            "Comprobar precios",
+            "Controlla i prezzi",
+        ],
+    ),
+    (
+        "menu.watch_prices",
+        [
+            "Continuous price check",
+            "Verificação contínua de preços",
+            "Comprobación continua de precios",
+            "Controllo continuo dei prezzi",
+        ],
+    ),
+    (
+        "menu.sync_prices",
+        [
+            "Sync prices",
+            "Sincronizar preços",
+            "Sincronizar precios",
+            "Sincronizza i prezzi",
+        ],
+    ),
+    (
+        "menu.sync_new",
+        [
+            "Sync new expansions",
+            "Sincronizar expansões novas",
+            "Sincronizar expansiones nuevas",
+            "Sincronizza le nuove espansioni",
+        ],
+    ),
+    (
+        "menu.refresh_blueprints",
+        [
+            "Refresh all blueprints",
+            "Atualizar todos os blueprints",
+            "Actualizar todos los blueprints",
+            "Aggiorna tutti i blueprint",
+        ],
+    ),
+    (
+        "menu.fantoccini",
+        [
+            "Check with fantoccini",
+            "Verificar com fantoccini",
+            "Comprobar con fantoccini",
+            "Controlla con fantoccini",
+        ],
+    ),
+    (
+        "menu.exit",
+        [
+            "Exit",
+            "Sair",
+            "Salir",
+            "Esci",
+        ],
+    ),
+    (
+        "menu.prompt",
+        [
+            "What would you like to do? (wishlist: {{list}})",
+            "O que você quer fazer? (wishlist: {{list}})",
+            "¿Qué quieres hacer? (wishlist: {{list}})",
+            "Cosa vuoi fare? (wishlist: {{list}})",
+        ],
+    ),
+    (
+        "menu.help",
+        [
+            "Use arrow keys to navigate, and Enter to select",
+            "Use as setas para navegar e Enter para escolher",
+            "Usa las flechas para moverte y Enter para elegir",
+            "Usa le frecce per muoverti e Invio per scegliere",
+        ],
+    ),
+    (
+        "menu.error",
+        [
+            "There was an error, please try again",
+            "Houve um erro, tente de novo",
+            "Hubo un error, inténtalo de nuevo",
+            "Si è verificato un errore, riprova",
+        ],
+    ),
+    (
+        "bot.not_allowed",
+        [
+            "This chat is not allowed to use the bot. Ask an admin to send /adduser {{chat_id}}",
+            "Este chat não tem acesso ao bot. Peça a um admin para enviar /adduser {{chat_id}}",
+            "Este chat no tiene acceso al bot. Pide a un admin que envíe /adduser {{chat_id}}",
+            "Questa chat non ha accesso al bot. Chiedi a un admin di inviare /adduser {{chat_id}}",
+        ],
+    ),
+    (
+        "bot.not_allowed_short",
+        [
+            "This chat is not allowed to use the bot",
+            "Este chat não tem acesso ao bot",
+            "Este chat no tiene acceso al bot",
+            "Questa chat non ha accesso al bot",
+        ],
+    ),
+    (
+        "bot.admins_only",
+        [
+            "Only admins can do that",
+            "Só admins podem fazer isso",
+            "Solo los admins pueden hacer eso",
+            "Solo gli admin possono farlo",
+        ],
+    ),
+    (
+        "bot.help.title",
+        [
+            "Wishlist commands:",
+            "Comandos da wishlist:",
+            "Comandos de la wishlist:",
+            "Comandi della wishlist:",
+        ],
+    ),
+    (
+        "bot.help.admin_title",
+        [
+            "Admin commands:",
+            "Comandos de admin:",
+            "Comandos de admin:",
+            "Comandi admin:",
+        ],
+    ),
+    (
+        "bot.help.help",
+        [
+            "show this help",
+            "mostra esta ajuda",
+            "muestra esta ayuda",
+            "mostra questo aiuto",
+        ],
+    ),
+    (
+        "bot.help.add",
+        [
+            "add a card: /add <card> [(set)] [target price]",
+            "adiciona uma carta: /add <carta> [(expansão)] [preço desejado]",
+            "añade una carta: /add <carta> [(expansión)] [precio objetivo]",
+            "aggiunge una carta: /add <carta> [(espansione)] [prezzo desiderato]",
+        ],
+    ),
+    (
+        "bot.help.list",
+        [
+            "show the wishlist",
+            "mostra a wishlist",
+            "muestra la wishlist",
+            "mostra la wishlist",
+        ],
+    ),
+    (
+        "bot.help.remove",
+        [
+            "remove cards: /remove <number from /list or card>",
+            "remove cartas: /remove <número da /list ou carta>",
+            "elimina cartas: /remove <número de /list o carta>",
+            "rimuove carte: /remove <numero da /list o carta>",
+        ],
+    ),
+    (
+        "bot.help.target",
+        [
+            "change the target price: /target <number or card> <price>",
+            "muda o preço desejado: /target <número ou carta> <preço>",
+            "cambia el precio objetivo: /target <número o carta> <precio>",
+            "cambia il prezzo desiderato: /target <numero o carta> <prezzo>",
+        ],
+    ),
+    (
+        "bot.help.check",
+        [
+            "check the prices now",
+            "verifica os preços agora",
+            "comprueba los precios ahora",
+            "controlla i prezzi ora",
+        ],
+    ),
+    (
+        "bot.help.history",
+        [
+            "price history: /history <number or card>",
+            "histórico de preços: /history <número ou carta>",
+            "historial de precios: /history <número o carta>",
+            "storico dei prezzi: /history <numero o carta>",
+        ],
+    ),
+    (
+        "bot.help.alerts",
+        [
+            "show or change the alerts of your wishlist: /alerts [on|off|default|<channel>...]",
+            "mostra ou muda os alertas da sua wishlist: /alerts [on|off|default|<canal>...]",
+            "muestra o cambia las alertas de tu wishlist: /alerts [on|off|default|<canal>...]",
+            "mostra o cambia gli avvisi della tua wishlist: /alerts [on|off|default|<canale>...]",
+        ],
+    ),
+    (
+        "bot.help.adduser",
+        [
+            "allow a chat: /adduser <chat id> [name]",
+            "autoriza um chat: /adduser <id do chat> [nome]",
+            "autoriza un chat: /adduser <id del chat> [nombre]",
+            "autorizza una chat: /adduser <id della chat> [nome]",
+        ],
+    ),
+    (
+        "bot.help.addadmin",
+        [
+            "allow a chat as an admin: /addadmin <chat id> [name]",
+            "autoriza um chat como admin: /addadmin <id do chat> [nome]",
+            "autoriza un chat como admin: /addadmin <id del chat> [nombre]",
+            "autorizza una chat come admin: /addadmin <id della chat> [nome]",
+        ],
+    ),
+    (
+        "bot.help.removeuser",
+        [
+            "revoke a chat: /removeuser <chat id>",
+            "revoga um chat: /removeuser <id do chat>",
+            "revoca un chat: /removeuser <id del chat>",
+            "revoca una chat: /removeuser <id della chat>",
+        ],
+    ),
+    (
+        "bot.help.users",
+        [
+            "list the allowed chats",
+            "lista os chats autorizados",
+            "lista los chats autorizados",
+            "elenca le chat autorizzate",
+        ],
+    ),
+    (
+        "bot.usage_adduser",
+        [
+            "Usage: /adduser <chat id> [name] or /addadmin <chat id> [name]",
+            "Uso: /adduser <id do chat> [nome] ou /addadmin <id do chat> [nome]",
+            "Uso: /adduser <id del chat> [nombre] o /addadmin <id del chat> [nombre]",
+            "Uso: /adduser <id della chat> [nome] o /addadmin <id della chat> [nome]",
+        ],
+    ),
+    (
+        "bot.usage_removeuser",
+        [
+            "Usage: /removeuser <chat id>",
+            "Uso: /removeuser <id do chat>",
+            "Uso: /removeuser <id del chat>",
+            "Uso: /removeuser <id della chat>",
+        ],
+    ),
+    (
+        "bot.users_owner",
+        [
+            "owner ({{chat_id}}): admin, wishlist {{list}}",
+            "dono ({{chat_id}}): admin, wishlist {{list}}",
+            "propietario ({{chat_id}}): admin, wishlist {{list}}",
+            "proprietario ({{chat_id}}): admin, wishlist {{list}}",
+        ],
+    ),
+    (
+        "bot.users_line",
+        [
+            "{{name}} ({{chat_id}}): {{#if admin}}admin{{else}}user{{/if}}, wishlist {{list}}",
+            "{{name}} ({{chat_id}}): {{#if admin}}admin{{else}}usuário{{/if}}, wishlist {{list}}",
+            "{{name}} ({{chat_id}}): {{#if admin}}admin{{else}}usuario{{/if}}, wishlist {{list}}",
+            "{{name}} ({{chat_id}}): {{#if admin}}admin{{else}}utente{{/if}}, wishlist {{list}}",
+        ],
+    ),
+    (
+        "bot.no_users",
+        [
+            "No chat can use the bot yet",
+            "Nenhum chat pode usar o bot ainda",
+            "Ningún chat puede usar el bot todavía",
+            "Nessuna chat può ancora usare il bot",
+        ],
+    ),
+    (
+        "bot.choice_expired",
+        [
+            "This option is no longer available, send /add again",
+            "Esta opção não está mais disponível, envie /add de novo",
+            "Esta opción ya no está disponible, envía /add otra vez",
+            "Questa opzione non è più disponibile, invia di nuovo /add",
+        ],
+    ),
+    (
+        "bot.usage_add",
+        [
+            "Usage: /add <card> [(set)] [target price]",
+            "Uso: /add <carta> [(expansão)] [preço desejado]",
+            "Uso: /add <carta> [(expansión)] [precio objetivo]",
+            "Uso: /add <carta> [(espansione)] [prezzo desiderato]",
+        ],
+    ),
+    (
+        "bot.versions",
+        [
+            "{{card}} has {{count}} versions, pick one:",
+            "{{card}} tem {{count}} versões, escolha uma:",
+            "{{card}} tiene {{count}} versiones, elige una:",
+            "{{card}} ha {{count}} versioni, scegline una:",
+        ],
+    ),
+    (
+        "bot.did_you_mean",
+        [
+            "No card named \"{{card}}\", did you mean:",
+            "Nenhuma carta chamada \"{{card}}\", você quis dizer:",
+            "Ninguna carta llamada \"{{card}}\". Quizás quisiste decir:",
+            "Nessuna carta chiamata \"{{card}}\", forse intendevi:",
+        ],
+    ),
+    (
+        "bot.too_many",
+        [
+            "Only the first {{count}} are shown, add the set in parentheses to narrow it down.",
+            "Só as {{count}} primeiras aparecem, adicione a expansão entre parênteses para restringir.",
+            "Solo se muestran las {{count}} primeras, añade la expansión entre paréntesis para acotar.",
+            "Sono mostrate solo le prime {{count}}, aggiungi l'espansione tra parentesi per restringere.",
+        ],
+    ),
+    (
+        "bot.no_listings",
+        [
+            "{{card}} has no listings matching the preferences, add it with a target price",
+            "{{card}} não tem anúncios que atendam às preferências, adicione com um preço desejado",
+            "{{card}} no tiene anuncios que cumplan las preferencias, añádela con un precio objetivo",
+            "{{card}} non ha inserzioni che rispettano le preferenze, aggiungila con un prezzo desiderato",
+        ],
+    ),
+    (
+        "bot.duplicate",
+        [
+            "{{item}} is already in the wishlist, use /target to change it",
+            "{{item}} já está na wishlist, use /target para mudá-lo",
+            "{{item}} ya está en la wishlist, usa /target para cambiarlo",
+            "{{item}} è già nella wishlist, usa /target per modificarlo",
+        ],
+    ),
+    (
+        "bot.empty",
+        [
+            "The wishlist is empty, add cards with /add",
+            "A wishlist está vazia, adicione cartas com /add",
+            "La wishlist está vacía, añade cartas con /add",
+            "La wishlist è vuota, aggiungi carte con /add",
+        ],
+    ),
+    (
+        "bot.usage_remove",
+        [
+            "Usage: /remove <number from /list or card>",
+            "Uso: /remove <número da /list ou carta>",
+            "Uso: /remove <número de /list o carta>",
+            "Uso: /remove <numero da /list o carta>",
+        ],
+    ),
+    (
+        "bot.usage_target",
+        [
+            "Usage: /target <number from /list or card> <price>",
+            "Uso: /target <número da /list ou carta> <preço>",
+            "Uso: /target <número de /list o carta> <precio>",
+            "Uso: /target <numero da /list o carta> <prezzo>",
+        ],
+    ),
+    (
+        "bot.target_ambiguous",
+        [
+            "{{card}} matches {{count}} cards, use its number from /list",
+            "{{card}} corresponde a {{count}} cartas, use o número da /list",
+            "{{card}} coincide con {{count}} cartas, usa su número de /list",
+            "{{card}} corrisponde a {{count}} carte, usa il suo numero da /list",
+        ],
+    ),
+    (
+        "bot.usage_alerts",
+        [
+            "Usage: /alerts [on|off|default|<channel>...]",
+            "Uso: /alerts [on|off|default|<canal>...]",
+            "Uso: /alerts [on|off|default|<canal>...]",
+            "Uso: /alerts [on|off|default|<canale>...]",
+        ],
+    ),
+    (
+        "bot.alerts_admin_only",
+        [
+            "Only admins can send alerts to another chat or to a Discord, Slack, email, ntfy, Gotify or webhook channel; use telegram or default",
+            "Só admins podem mandar alertas para outro chat ou para um canal Discord, Slack, email, ntfy, Gotify ou webhook; use telegram ou default",
+            "Solo los admins pueden enviar alertas a otro chat o a un canal Discord, Slack, email, ntfy, Gotify o webhook; usa telegram o default",
+            "Solo gli admin possono inviare avvisi a un'altra chat o a un canale Discord, Slack, email, ntfy, Gotify o webhook; usa telegram o default",
+        ],
+    ),
+    (
+        "bot.alerts",
+        [
+            "Alerts of the {{list}} wishlist are {{#if on}}on{{else}}off{{/if}}{{#if channels}}, sent to {{channels}}{{/if}}",
+            "Os alertas da wishlist {{list}} estão {{#if on}}ligados{{else}}desligados{{/if}}{{#if channels}}, enviados para {{channels}}{{/if}}",
+            "Las alertas de la wishlist {{list}} están {{#if on}}activadas{{else}}desactivadas{{/if}}{{#if channels}}, enviadas a {{channels}}{{/if}}",
+            "Gli avvisi della wishlist {{list}} sono {{#if on}}attivi{{else}}disattivati{{/if}}{{#if channels}}, inviati a {{channels}}{{/if}}",
+        ],
+    ),
+    (
+        "bot.usage_history",
+        [
+            "Usage: /history <number from /list or card>",
+            "Uso: /history <número da /list ou carta>",
+            "Uso: /history <número de /list o carta>",
+            "Uso: /history <numero da /list o carta>",
+        ],
+    ),
+];
+
+// O template de uma mensagem; chaves desconhecidas são erro de programação
+pub fn message(locale: Locale, key: &str) -> &'static str {
+    MESSAGES
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, texts)| texts[locale.index()])
+        .unwrap_or_else(|| panic!("missing message {:?}", key))
+}
+
+pub fn tr_in(locale: Locale, key: &str, vars: &[(&str, String)]) -> String {
+    match Template::parse(message(locale, key)) {
+        Ok(template) => template.render(vars, &Plain),
+        Err(e) => panic!("broken message {:?}: {}", key, e),
+    }
+}
+
+pub fn tr(key: &str, vars: &[(&str, String)]) -> String {
+    tr_in(locale(), key, vars)
+}
+
+// tr!("item.added", item = describe_item(&item))
+#[macro_export]
+macro_rules! tr {
+    ($key:expr) => {
+        $crate::i18n::tr($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::tr($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_message_parses_in_every_locale() {
+        for (key, texts) in MESSAGES {
+            for (locale, text) in LOCALES.iter().zip(texts) {
+                assert!(!text.is_empty(), "{} {}", key, locale);
+                Template::parse(text).unwrap_or_else(|e| panic!("{} {}: {}", key, locale, e));
+            }
+            // As traduções usam as mesmas variáveis
+            let english = Template::parse(texts[0]).unwrap();
+            for text in &texts[1..] {
+                assert_eq!(
+                    Template::parse(text).unwrap().variables(),
+                    english.variables(),
+                    "{}",
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn messages_follow_the_locale() {
+        let vars = [("item", "Sol Ring".to_string())];
+        assert_eq!(tr_in(Locale::En, "item.added", &vars), "Added Sol Ring");
+        assert_eq!(
+            tr_in(Locale::PtBr, "item.added", &vars),
+            "Adicionado Sol Ring"
+        );
+        assert_eq!("pt_BR".parse::<Locale>().unwrap(), Locale::PtBr);
+        assert!("fr".parse::<Locale>().is_err());
+    }
+}
@@ -0,0 +1,22 @@
+pub mod api;
+pub mod bot;
+pub mod cache;
+pub mod decklist;
+pub mod error;
+pub mod history;
+pub mod i18n;
+pub mod notify;
+pub mod preferences;
+pub mod pricing;
+pub mod settings;
+pub mod slug;
+pub mod storage;
+pub mod template;
+pub mod wishlist;
+
+pub use api::CardTraderClient;
+pub use cache::BlueprintCache;
+pub use error::AppError;
+pub use pricing::{CardRef, PriceOutcome, PriceQuote, PriceSource};
+pub use storage::Storage;
+pub use wishlist::WishlistItem;
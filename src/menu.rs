@@ -0,0 +1,806 @@
+use crate::cli;
+use cardtrader_client::api::blueprint::BlueprintData;
+use cardtrader_client::api::game::Game;
+use cardtrader_client::api::CardTraderClient;
+use cardtrader_client::bot::{self, BotContext};
+use cardtrader_client::cache::sync::{self, SyncMode};
+use cardtrader_client::cache::{search, BlueprintCache};
+use cardtrader_client::decklist::{self, DeckFormat, DefaultTarget, ImportOptions};
+use cardtrader_client::error::AppError;
+use cardtrader_client::notify::Channel;
+use cardtrader_client::preferences::{Condition, FoilPreference, Preferences};
+use cardtrader_client::pricing::{self, CardRef, FantocciniPriceSource, PriceOutcome, PriceSource};
+use cardtrader_client::settings::Settings;
+use cardtrader_client::storage::{self, Storage};
+use cardtrader_client::tr;
+use cardtrader_client::wishlist::{
+    self, add_to_wishlist, SortKey, WishlistFilter, WishlistInfo, WishlistItem, DEFAULT_WISHLIST,
+};
+use futures::future::join_all;
+use inquire::validator::Validation;
+use inquire::{Confirm, CustomType, CustomUserError, InquireError, MultiSelect, Select, Text};
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task;
+
+const MAX_CONCURRENT_CHECKS: usize = 10;
+const SEARCH_RESULTS: usize = 25;
+
+// Uma opção de menu; o texto é traduzido na hora de mostrar
+#[derive(Clone)]
+struct Choice<T> {
+    value: T,
+    key: &'static str,
+}
+
+impl<T> fmt::Display for Choice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&tr!(self.key))
+    }
+}
+
+fn choices<T: Clone>(options: &[(T, &'static str)]) -> Vec<Choice<T>> {
+    options
+        .iter()
+        .map(|(value, key)| Choice {
+            value: value.clone(),
+            key,
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum MenuAction {
+    AddCard,
+    ImportDecklist,
+    ManageWishlist,
+    Wishlists,
+    ChangeGame,
+    DefaultPreferences,
+    CheckPrices,
+    WatchPrices,
+    SyncPrices,
+    SyncNewExpansions,
+    RefreshBlueprints,
+    CheckWithFantoccini,
+    Exit,
+}
+
+const MAIN_MENU: [(MenuAction, &str); 13] = [
+    (MenuAction::AddCard, "menu.add_card"),
+    (MenuAction::ImportDecklist, "menu.import_decklist"),
+    (MenuAction::ManageWishlist, "menu.manage_wishlist"),
+    (MenuAction::Wishlists, "menu.wishlists_entry"),
+    (MenuAction::ChangeGame, "menu.change_game"),
+    (MenuAction::DefaultPreferences, "menu.default_preferences"),
+    (MenuAction::CheckPrices, "menu.check_prices"),
+    (MenuAction::WatchPrices, "menu.watch_prices"),
+    (MenuAction::SyncPrices, "menu.sync_prices"),
+    (MenuAction::SyncNewExpansions, "menu.sync_new"),
+    (MenuAction::RefreshBlueprints, "menu.refresh_blueprints"),
+    (MenuAction::CheckWithFantoccini, "menu.fantoccini"),
+    (MenuAction::Exit, "menu.exit"),
+];
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum WishlistAction {
+    Show,
+    Edit,
+    Remove,
+    Merge,
+    Back,
+}
+
+const WISHLIST_MENU: [(WishlistAction, &str); 5] = [
+    (WishlistAction::Show, "menu.show_wishlist"),
+    (WishlistAction::Edit, "menu.edit_card"),
+    (WishlistAction::Remove, "menu.remove_cards"),
+    (WishlistAction::Merge, "menu.merge_cards"),
+    (WishlistAction::Back, "menu.back"),
+];
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum WishlistsAction {
+    Switch,
+    Create,
+    Rename,
+    Delete,
+    Copy,
+    Alerts,
+    Back,
+}
+
+const WISHLISTS_MENU: [(WishlistsAction, &str); 7] = [
+    (WishlistsAction::Switch, "menu.switch_wishlist"),
+    (WishlistsAction::Create, "menu.create_wishlist"),
+    (WishlistsAction::Rename, "menu.rename_wishlist"),
+    (WishlistsAction::Delete, "menu.delete_wishlist"),
+    (WishlistsAction::Copy, "menu.copy_cards"),
+    (WishlistsAction::Alerts, "menu.alert_settings"),
+    (WishlistsAction::Back, "menu.back"),
+];
+
+// Busca por nome (prefixo, sem acentos, tolerando erros de digitação) ou por
+// código da expansão + número de coleção. Uma busca vazia cancela.
+fn search_card(
+    cache: &BlueprintCache,
+    game_id: Option<u32>,
+) -> Result<Option<(String, Vec<BlueprintData>)>, InquireError> {
+    loop {
+        let query = Text::new(&tr!("menu.search"))
+            .with_help_message(&tr!("menu.search_help"))
+            .prompt()?;
+        if query.trim().is_empty() {
+            return Ok(None);
+        }
+
+        if let Some((code, number)) = search::parse_set_and_number(&query) {
+            let found = cache.find_by_set_and_number(game_id, &code, &number);
+            if let Some(first) = found.first() {
+                return Ok(Some((first.card_name.clone(), found)));
+            }
+        }
+
+        let names: Vec<String> = cache
+            .search(game_id, &query, SEARCH_RESULTS)
+            .into_iter()
+            .map(|hit| hit.name)
+            .collect();
+        if names.is_empty() {
+            println!("{}", tr!("menu.no_match", query = query));
+            continue;
+        }
+        let card_name = Select::new(&tr!("menu.select_name"), names).prompt()?;
+        if let Some(versions) = cache.get_blueprints_by_name(game_id, &card_name) {
+            return Ok(Some((card_name, versions)));
+        }
+    }
+}
+
+async fn list_and_select_cards(
+    cache: &BlueprintCache,
+    storage: &dyn Storage,
+    list: &str,
+    source: Arc<dyn PriceSource>,
+    game_id: Option<u32>,
+) -> Result<(), AppError> {
+    // Usuário busca a carta pelo nome ou pelo código da expansão + número
+    match search_card(cache, game_id) {
+        Ok(Some((card_name, versions))) => {
+            let mut version_descriptions: Vec<String> = versions
+                .iter()
+                .map(|bp| {
+                    format!(
+                        "{} ({}) - {}",
+                        bp.card_name, bp.collector_number, bp.expansion_name,
+                    )
+                })
+                .collect();
+            version_descriptions.push(tr!("menu.all_versions"));
+
+            // Usuário seleciona uma versão da carta; a última opção adiciona
+            // todas
+            let select_version =
+                Select::new(&tr!("menu.select_version"), version_descriptions).raw_prompt();
+
+            match select_version {
+                Ok(version) => {
+                    if version.index == versions.len() {
+                        let mut tasks = Vec::new();
+                        let defaults = Settings::load()?.preferences;
+                        let pb = indicatif::ProgressBar::new(versions.len() as u64);
+                        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_CHECKS));
+                        println!("{}", tr!("menu.adding_all", card = card_name));
+                        for version in &versions {
+                            let semaphore_clone = Arc::clone(&semaphore);
+                            let version_clone = version.clone();
+                            let pb_clone = pb.clone();
+                            let source = Arc::clone(&source);
+                            let card = CardRef::from(version).with_preferences(defaults.clone(), 1);
+                            let task = task::spawn(async move {
+                                let _permit = semaphore_clone.acquire().await.unwrap();
+
+                                let quote = match source.fetch_quote(&card).await? {
+                                    PriceOutcome::Quote(quote) => quote,
+                                    PriceOutcome::NoListings => {
+                                        pb_clone.inc(1);
+                                        println!(
+                                            "{}",
+                                            tr!(
+                                                "menu.version_skipped",
+                                                card = version_clone.card_name,
+                                                set = version_clone.expansion_name
+                                            )
+                                        );
+                                        return Ok(None);
+                                    }
+                                };
+
+                                // O preço atual vira o preço desejado inicial
+                                let mut item =
+                                    WishlistItem::from_blueprint(&version_clone, quote.amount);
+                                item.record_quote(&quote);
+                                pb_clone.inc(1);
+                                Ok(Some(item)) as Result<Option<WishlistItem>, AppError>
+                            });
+                            tasks.push(task);
+                        }
+                        let results = join_all(tasks).await;
+                        pb.finish_with_message(tr!("menu.all_added"));
+                        // Grava todas as versões numa única escrita
+                        let mut items = Vec::new();
+                        for result in results {
+                            match result {
+                                Ok(Ok(Some(item))) => items.push(item),
+                                Ok(Ok(None)) => {}
+                                Ok(Err(e)) => println!("{}", tr!("menu.version_failed", error = e)),
+                                Err(e) => println!("{}", tr!("menu.version_failed", error = e)),
+                            }
+                        }
+                        let duplicates = wishlist::add_items(storage, list, items)?;
+                        if !duplicates.is_empty() {
+                            println!(
+                                "{}",
+                                tr!("menu.versions_already_in", count = duplicates.len())
+                            );
+                        }
+                    } else {
+                        let selected_version = &versions[version.index];
+                        if let Some(image_url) = &selected_version.image_url {
+                            println!("{}", tr!("menu.image", url = image_url));
+                        }
+
+                        let defaults = Settings::load()?.preferences;
+                        let quantity = CustomType::<u32>::new(&tr!("menu.quantity"))
+                            .with_default(1)
+                            .with_validator(valid_quantity)
+                            .prompt()?;
+                        let preferences = if Confirm::new(&tr!(
+                            "menu.use_default_preferences",
+                            preferences = defaults
+                        ))
+                        .with_default(true)
+                        .prompt()?
+                        {
+                            None
+                        } else {
+                            Some(prompt_preferences(&defaults)?)
+                        };
+                        let card = CardRef::from(selected_version)
+                            .with_preferences(preferences.clone().unwrap_or(defaults), quantity);
+
+                        let mut item = match source.fetch_quote(&card).await? {
+                            PriceOutcome::Quote(quote) => {
+                                let mut item =
+                                    WishlistItem::from_blueprint(selected_version, quote.amount);
+                                item.record_quote(&quote);
+                                item
+                            }
+                            PriceOutcome::NoListings => {
+                                let target_price =
+                                    CustomType::<f64>::new(&tr!("menu.no_listings_target"))
+                                        .with_validator(valid_target)
+                                        .prompt()?;
+                                WishlistItem::from_blueprint(selected_version, target_price)
+                            }
+                        };
+                        item.quantity = quantity;
+                        item.preferences = preferences;
+
+                        if !add_to_wishlist(storage, list, item)? {
+                            println!("{}", tr!("menu.already_in"));
+                        }
+                    }
+                }
+                Err(_) => println!("{}", tr!("menu.version_not_selected")),
+            }
+        }
+        Ok(None) => println!("{}", tr!("menu.no_card")),
+        Err(_) => println!("{}", tr!("menu.name_not_selected")),
+    }
+
+    Ok(())
+}
+
+async fn import_decklist(
+    cache: &BlueprintCache,
+    storage: &dyn Storage,
+    list: &str,
+    source: Arc<dyn PriceSource>,
+    game_id: Option<u32>,
+) -> Result<(), AppError> {
+    let path = Text::new(&tr!("menu.decklist_file"))
+        .with_help_message(&tr!("menu.decklist_help"))
+        .prompt()?;
+    let path = PathBuf::from(path.trim());
+    let content = std::fs::read_to_string(&path)?;
+    let lines = decklist::parse(DeckFormat::detect(&path, &content), &content)?;
+
+    let discount = CustomType::<f64>::new(&tr!("menu.discount"))
+        .with_default(0.0)
+        .prompt()?;
+    let target = if discount > 0.0 {
+        DefaultTarget::Discount(discount)
+    } else {
+        DefaultTarget::CurrentPrice
+    };
+
+    let options = ImportOptions {
+        target,
+        preferences: None,
+    };
+    let report = decklist::import(storage, list, cache, source, game_id, lines, options).await?;
+    cli::print_import_report(&report);
+    Ok(())
+}
+
+fn valid_quantity(quantity: &u32) -> Result<Validation, CustomUserError> {
+    Ok(match wishlist::invalid_values(None, Some(*quantity)) {
+        Some(error) => Validation::Invalid(error.into()),
+        None => Validation::Valid,
+    })
+}
+
+fn valid_target(target_price: &f64) -> Result<Validation, CustomUserError> {
+    Ok(match wishlist::invalid_values(Some(*target_price), None) {
+        Some(error) => Validation::Invalid(error.into()),
+        None => Validation::Valid,
+    })
+}
+
+fn edit_item(item: &mut WishlistItem) -> Result<(), InquireError> {
+    item.target_price = CustomType::<f64>::new(&tr!("menu.target_price"))
+        .with_default(item.target_price)
+        .with_validator(valid_target)
+        .prompt()?;
+    item.quantity = CustomType::<u32>::new(&tr!("menu.quantity"))
+        .with_default(item.quantity)
+        .with_validator(valid_quantity)
+        .prompt()?;
+    if Confirm::new(&tr!("menu.change_preferences"))
+        .with_default(false)
+        .prompt()?
+    {
+        let current = item.preferences.clone().unwrap_or_default();
+        item.preferences = Some(prompt_preferences(&current)?);
+    }
+    Ok(())
+}
+
+fn manage_wishlist(storage: &dyn Storage, list: &str) -> Result<(), AppError> {
+    loop {
+        let choice = Select::new(&tr!("menu.wishlist"), choices(&WISHLIST_MENU))
+            .prompt()?
+            .value;
+        let mut items = wishlist::load_wishlist(storage, list)?;
+        if items.is_empty() && choice != WishlistAction::Back {
+            println!("{}", tr!("menu.empty"));
+            return Ok(());
+        }
+        match choice {
+            WishlistAction::Show => {
+                let sorts = choices(&[
+                    (SortKey::Name, "menu.sort_name"),
+                    (SortKey::Price, "menu.sort_price"),
+                    (SortKey::Gap, "menu.sort_gap"),
+                ]);
+                let sort = Select::new(&tr!("menu.sort_by"), sorts).prompt()?.value;
+                wishlist::sort_items(&mut items, sort);
+                for item in &items {
+                    match item.gap_percent() {
+                        Some(gap) => println!("{} ({:+.1}%)", wishlist::describe_item(item), gap),
+                        None => println!("{}", wishlist::describe_item(item)),
+                    }
+                }
+            }
+            WishlistAction::Edit => {
+                let labels: Vec<String> = items.iter().map(wishlist::describe_item).collect();
+                let selected = Select::new(&tr!("menu.which_card"), labels).raw_prompt()?;
+                edit_item(&mut items[selected.index])?;
+                storage.save_wishlist(list, &items)?;
+                println!(
+                    "{}",
+                    tr!(
+                        "item.updated",
+                        item = wishlist::describe_item(&items[selected.index])
+                    )
+                );
+            }
+            WishlistAction::Remove => {
+                let labels: Vec<String> = items.iter().map(wishlist::describe_item).collect();
+                let selected: Vec<usize> = MultiSelect::new(&tr!("menu.which_cards"), labels)
+                    .raw_prompt()?
+                    .into_iter()
+                    .map(|option| option.index)
+                    .collect();
+                if selected.is_empty() {
+                    continue;
+                }
+                let kept: Vec<WishlistItem> = items
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(index, _)| !selected.contains(index))
+                    .map(|(_, item)| item)
+                    .collect();
+                storage.save_wishlist(list, &kept)?;
+                println!("{}", tr!("menu.removed", count = selected.len()));
+            }
+            WishlistAction::Merge => {
+                let merged = wishlist::dedup_wishlist(storage, list)?;
+                println!("{}", tr!("dedup.merged", count = merged));
+            }
+            WishlistAction::Back => return Ok(()),
+        }
+    }
+}
+
+fn prompt_alerts(info: &mut WishlistInfo) -> Result<(), InquireError> {
+    info.alerts.enabled = Confirm::new(&tr!("menu.send_alerts"))
+        .with_default(info.alerts.enabled)
+        .prompt()?;
+    if info.alerts.enabled {
+        // Vazio usa o TELEGRAM_CHAT_ID padrão
+        info.alerts.telegram_chat_id = CustomType::<i64>::new(&tr!("menu.chat_id"))
+            .with_help_message(&tr!("menu.chat_id_help"))
+            .prompt_skippable()?;
+        let current: Vec<String> = info
+            .alerts
+            .channels
+            .iter()
+            .map(Channel::to_string)
+            .collect();
+        // Vazio usa os canais padrão do settings.json
+        let channels = Text::new(&tr!("menu.channels"))
+            .with_initial_value(&current.join(" "))
+            .with_help_message(&tr!("menu.channels_help"))
+            .with_validator(|input: &str| {
+                Ok(
+                    match input
+                        .split_whitespace()
+                        .try_for_each(|spec| spec.parse::<Channel>().map(drop))
+                    {
+                        Ok(()) => Validation::Valid,
+                        Err(e) => Validation::Invalid(e.into()),
+                    },
+                )
+            })
+            .prompt()?;
+        info.alerts.channels = channels
+            .split_whitespace()
+            .filter_map(|spec| spec.parse().ok())
+            .collect();
+    }
+    Ok(())
+}
+
+// Troca, cria e organiza as wishlists nomeadas. `current` é a lista usada
+// pelas outras opções do menu.
+fn manage_wishlists(storage: &dyn Storage, current: &mut String) -> Result<(), AppError> {
+    loop {
+        let prompt = tr!("menu.wishlists", list = current);
+        let choice = Select::new(&prompt, choices(&WISHLISTS_MENU))
+            .prompt()?
+            .value;
+        if choice == WishlistsAction::Back {
+            return Ok(());
+        }
+        let names: Vec<String> = storage
+            .wishlists()?
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        let result = match choice {
+            WishlistsAction::Switch => {
+                *current = Select::new(&tr!("menu.which_wishlist"), names).prompt()?;
+                Ok(())
+            }
+            WishlistsAction::Create => {
+                let mut info = WishlistInfo::new(Text::new(&tr!("menu.name")).prompt()?.trim());
+                prompt_alerts(&mut info)?;
+                wishlist::create_wishlist(storage, &info).map(|_| *current = info.name)
+            }
+            WishlistsAction::Rename => {
+                let from = Select::new(&tr!("menu.which_wishlist"), names).prompt()?;
+                let to = Text::new(&tr!("menu.new_name")).prompt()?;
+                let to = to.trim();
+                wishlist::rename_wishlist(storage, &from, to).map(|_| {
+                    if *current == from {
+                        *current = to.to_string();
+                    }
+                })
+            }
+            WishlistsAction::Delete => {
+                let name = Select::new(&tr!("menu.which_wishlist"), names).prompt()?;
+                if !Confirm::new(&tr!("menu.confirm_delete", list = name))
+                    .with_default(false)
+                    .prompt()?
+                {
+                    continue;
+                }
+                wishlist::delete_wishlist(storage, &name).map(|_| {
+                    if *current == name {
+                        *current = DEFAULT_WISHLIST.to_string();
+                    }
+                })
+            }
+            WishlistsAction::Copy => {
+                let from = Select::new(&tr!("menu.copy_from"), names.clone()).prompt()?;
+                let to = Select::new(&tr!("menu.copy_to"), names).prompt()?;
+                let card = Text::new(&tr!("menu.copy_only")).prompt()?;
+                let filter = WishlistFilter {
+                    card_name: Some(card.trim().to_string()).filter(|card| !card.is_empty()),
+                    expansion_name: None,
+                    blueprint_id: None,
+                };
+                wishlist::copy_items(storage, &from, &to, &filter).map(|(copied, duplicates)| {
+                    println!(
+                        "{}",
+                        tr!(
+                            "menu.copied",
+                            count = copied,
+                            duplicates = duplicates.len(),
+                            list = to
+                        )
+                    )
+                })
+            }
+            _ => {
+                let name = Select::new(&tr!("menu.which_wishlist"), names).prompt()?;
+                let mut info = wishlist::get_wishlist(storage, &name)?;
+                prompt_alerts(&mut info)?;
+                storage.save_wishlist_info(&info)
+            }
+        };
+        // Nome inválido ou repetido não encerra o menu
+        if let Err(e) = result {
+            println!("{}", e);
+        }
+    }
+}
+
+// Pergunta as preferências partindo das atuais
+fn prompt_preferences(current: &Preferences) -> Result<Preferences, InquireError> {
+    // A primeira opção aceita qualquer condição
+    let mut conditions = vec![tr!("menu.any_condition")];
+    conditions.extend(["NM", "SP", "MP", "PL", "HP", "PO"].map(str::to_string));
+    let cursor = current
+        .min_condition
+        .and_then(|condition| conditions.iter().position(|c| *c == condition.code()))
+        .unwrap_or(0);
+    let min_condition = Select::new(&tr!("menu.condition"), conditions)
+        .with_starting_cursor(cursor)
+        .prompt()?
+        .parse::<Condition>()
+        .ok();
+
+    let languages = Text::new(&tr!("menu.languages"))
+        .with_default(&current.languages.join(","))
+        .with_help_message(&tr!("menu.languages_help"))
+        .prompt()?
+        .split(',')
+        .map(|language| language.trim().to_lowercase())
+        .filter(|language| !language.is_empty())
+        .collect();
+
+    let foils = choices(&[
+        (FoilPreference::Any, "menu.foil_any"),
+        (FoilPreference::Foil, "menu.foil_only"),
+        (FoilPreference::NonFoil, "menu.non_foil_only"),
+    ]);
+    let cursor = foils
+        .iter()
+        .position(|foil| foil.value == current.foil)
+        .unwrap_or(0);
+    let foil = Select::new(&tr!("menu.foil"), foils)
+        .with_starting_cursor(cursor)
+        .prompt()?
+        .value;
+
+    let zero_only = Confirm::new(&tr!("menu.zero_only"))
+        .with_default(current.zero_only)
+        .prompt()?;
+
+    Ok(Preferences {
+        min_condition,
+        languages,
+        foil,
+        zero_only,
+    })
+}
+
+// Pergunta o jogo e oferece salvá-lo como padrão
+fn choose_game(
+    games: &[Game],
+    cache: &BlueprintCache,
+    settings: &mut Settings,
+) -> Result<Option<u32>, AppError> {
+    // Só oferece jogos que têm blueprints baixados
+    let available = cache.game_ids();
+    let options: Vec<String> = games
+        .iter()
+        .filter(|game| available.is_empty() || available.contains(&game.id))
+        .map(|game| game.display_name.clone())
+        .collect();
+    if options.is_empty() {
+        return Ok(None);
+    }
+
+    let choice = Select::new(&tr!("menu.which_game"), options).prompt()?;
+    let game = games.iter().find(|game| game.display_name == choice);
+    let game_id = game.map(|game| game.id);
+    if settings.default_game_id != game_id
+        && Confirm::new(&tr!("menu.default_game"))
+            .with_default(true)
+            .prompt()?
+    {
+        settings.default_game_id = game_id;
+        settings.save()?;
+    }
+    Ok(game_id)
+}
+
+pub async fn run() -> Result<(), AppError> {
+    let api = CardTraderClient::from_env()?;
+    println!("{}", tr!("menu.loading"));
+
+    let expansions = api.expansions().await?;
+    let price_source = pricing::source_from_env(&api)?;
+    let storage = storage::from_env()?;
+
+    if storage.load_blueprints()?.is_empty() {
+        println!("{}", tr!("menu.downloading"));
+        let summary = sync::sync_blueprints(
+            &api,
+            storage.as_ref(),
+            expansions.clone(),
+            SyncMode::NewOnly,
+        )
+        .await?;
+        println!("{}", cli::describe_sync(&summary));
+    }
+
+    let mut blueprint_cache = Arc::new(BlueprintCache::new());
+    blueprint_cache.load_from_storage(storage.as_ref())?;
+
+    let user_name = whoami::username();
+    println!("{}", tr!("menu.welcome", user = user_name));
+
+    let games = api.games().await?;
+    let mut settings = Settings::load()?;
+    let mut game_id = match settings.default_game_id {
+        Some(game_id) => Some(game_id),
+        None => choose_game(&games, &blueprint_cache, &mut settings)?,
+    };
+    if let Some(game) = games.iter().find(|game| Some(game.id) == game_id) {
+        println!("{}", tr!("menu.adding_from", game = game.display_name));
+    }
+
+    // O bot atende a wishlist padrão enquanto o menu estiver aberto
+    if env::var("TELEGRAM_TOKEN").is_ok() {
+        let context = BotContext {
+            storage: Arc::clone(&storage),
+            cache: Arc::clone(&blueprint_cache),
+            source: Arc::clone(&price_source),
+            list: DEFAULT_WISHLIST.to_string(),
+            game_id,
+            owner_chat_id: bot::owner_chat_id()?,
+        };
+        tokio::spawn(async move {
+            if let Err(e) = bot::run(context).await {
+                println!("{}", tr!("menu.bot_stopped", error = e));
+            }
+        });
+    }
+
+    let mut list = DEFAULT_WISHLIST.to_string();
+
+    // Menu interativo
+    loop {
+        let prompt = tr!("menu.prompt", list = list);
+        let menu_ans = Select::new(&prompt, choices(&MAIN_MENU))
+            .with_help_message(&tr!("menu.help"))
+            .prompt();
+
+        match menu_ans {
+            Ok(choice) => match choice.value {
+                MenuAction::SyncNewExpansions | MenuAction::RefreshBlueprints => {
+                    let mode = if choice.value == MenuAction::SyncNewExpansions {
+                        SyncMode::NewOnly
+                    } else {
+                        SyncMode::All
+                    };
+                    let summary =
+                        sync::sync_blueprints(&api, storage.as_ref(), expansions.clone(), mode)
+                            .await?;
+                    println!("{}", cli::describe_sync(&summary));
+                    // Recarrega o cache com os blueprints atualizados
+                    blueprint_cache = Arc::new(BlueprintCache::new());
+                    blueprint_cache.load_from_storage(storage.as_ref())?;
+                }
+                MenuAction::AddCard => {
+                    list_and_select_cards(
+                        &blueprint_cache,
+                        storage.as_ref(),
+                        &list,
+                        Arc::clone(&price_source),
+                        game_id,
+                    )
+                    .await?
+                }
+                MenuAction::ImportDecklist => {
+                    import_decklist(
+                        &blueprint_cache,
+                        storage.as_ref(),
+                        &list,
+                        Arc::clone(&price_source),
+                        game_id,
+                    )
+                    .await?
+                }
+                MenuAction::ManageWishlist => manage_wishlist(storage.as_ref(), &list)?,
+                MenuAction::Wishlists => manage_wishlists(storage.as_ref(), &mut list)?,
+                MenuAction::DefaultPreferences => {
+                    settings.preferences = prompt_preferences(&settings.preferences)?;
+                    settings.save()?;
+                }
+                MenuAction::ChangeGame => {
+                    game_id = choose_game(&games, &blueprint_cache, &mut settings)?;
+                }
+                MenuAction::CheckPrices => {
+                    wishlist::check_wishlist_prices(
+                        storage.as_ref(),
+                        None,
+                        Arc::clone(&price_source),
+                    )
+                    .await?;
+                }
+                MenuAction::WatchPrices => {
+                    wishlist::continuous_check_prices(
+                        storage.as_ref(),
+                        None,
+                        Arc::clone(&price_source),
+                        Duration::from_secs(10),
+                    )
+                    .await?
+                }
+                MenuAction::SyncPrices => {
+                    wishlist::sync_prices(storage.as_ref(), None, Arc::clone(&price_source)).await?
+                }
+                MenuAction::CheckWithFantoccini => {
+                    wishlist::sync_prices(
+                        storage.as_ref(),
+                        None,
+                        Arc::new(FantocciniPriceSource::from_env()),
+                    )
+                    .await?
+                }
+                MenuAction::Exit => break,
+            },
+            Err(_) => println!("{}", tr!("menu.error")),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cardtrader_client::i18n::{self, Locale};
+
+    #[test]
+    fn menu_entries_follow_the_locale() {
+        let entries = i18n::with_locale(Locale::PtBr, || {
+            choices(&MAIN_MENU)
+                .iter()
+                .map(Choice::to_string)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(entries[0], "Adicionar carta");
+        assert_eq!(entries.last().unwrap(), "Sair");
+    }
+}
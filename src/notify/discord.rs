@@ -0,0 +1,93 @@
+use super::{deliver, titled_messages, Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Markup;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+
+// Limite de caracteres de uma mensagem de webhook do Discord
+const MAX_LENGTH: usize = 2000;
+
+pub struct DiscordNotifier {
+    webhook_url: String,
+}
+
+impl DiscordNotifier {
+    pub fn new(webhook_url: &str) -> DiscordNotifier {
+        DiscordNotifier {
+            webhook_url: webhook_url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for DiscordNotifier {
+    fn name(&self) -> &'static str {
+        "discord"
+    }
+
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        let client = Client::new();
+        let title = templates.title(alerts, &DiscordMarkdown);
+        let bodies = templates.bodies(alerts, &DiscordMarkdown);
+        for content in titled_messages(&title, &bodies, MAX_LENGTH) {
+            let body = json!({ "content": content });
+            deliver(self.name(), client.post(&self.webhook_url).json(&body)).await?;
+        }
+        Ok(())
+    }
+}
+
+struct DiscordMarkdown;
+
+impl Markup for DiscordMarkdown {
+    fn escape(&self, text: &str) -> String {
+        escape_markdown(text)
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("**{}**", text)
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '~' | '`' | '|' | '>' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::Template;
+
+    #[test]
+    fn card_names_cannot_break_the_formatting() {
+        let template = Template::parse("{{#bold}}{{card}}{{/bold}} ({{price}})").unwrap();
+        let vars = [
+            ("card", "Borborygmos *Enraged*".to_string()),
+            ("price", "€ 4.50".to_string()),
+        ];
+        assert_eq!(
+            template.render(&vars, &DiscordMarkdown),
+            "**Borborygmos \\*Enraged\\*** (€ 4.50)"
+        );
+    }
+
+    #[test]
+    fn long_titles_still_fit_in_a_message() {
+        let title = "€".repeat(MAX_LENGTH);
+        let bodies = "Sol Ring: 3.00\n".repeat(300);
+
+        let messages = titled_messages(&title, &bodies, MAX_LENGTH);
+
+        assert!(messages.len() > 1);
+        assert!(messages.iter().all(|message| message.len() <= MAX_LENGTH));
+        assert_eq!(messages.concat().matches("Sol Ring").count(), 300);
+    }
+}
@@ -0,0 +1,85 @@
+use super::{Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Plain;
+use async_trait::async_trait;
+use dotenv::dotenv;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::env;
+
+// O servidor SMTP vem do .env: SMTP_HOST, SMTP_PORT, SMTP_USERNAME,
+// SMTP_PASSWORD, SMTP_FROM e SMTP_SECURITY (starttls, tls ou none)
+pub struct EmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Mailbox,
+}
+
+fn parse_mailbox(var: &str, address: &str) -> Result<Mailbox, AppError> {
+    address
+        .parse()
+        .map_err(|e| AppError::Config(format!("{} is not a valid email address: {}", var, e)))
+}
+
+impl EmailNotifier {
+    pub fn from_env(to: &str) -> Result<EmailNotifier, AppError> {
+        dotenv().ok();
+        let host = env::var("SMTP_HOST").map_err(|e| AppError::missing_env("SMTP_HOST", e))?;
+        let from = env::var("SMTP_FROM").map_err(|e| AppError::missing_env("SMTP_FROM", e))?;
+        let security = env::var("SMTP_SECURITY").unwrap_or_else(|_| "starttls".to_string());
+
+        let mut transport = match security.to_lowercase().as_str() {
+            "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host),
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&host),
+            "none" => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+                &host,
+            )),
+            other => {
+                return Err(AppError::Config(format!(
+                    "SMTP_SECURITY must be starttls, tls or none, not {:?}",
+                    other
+                )))
+            }
+        }
+        .map_err(|e| AppError::notification("email", e))?;
+        if let Ok(port) = env::var("SMTP_PORT") {
+            let port = port
+                .parse()
+                .map_err(|_| AppError::Config("SMTP_PORT must be a valid port".to_string()))?;
+            transport = transport.port(port);
+        }
+        if let Ok(username) = env::var("SMTP_USERNAME") {
+            let password =
+                env::var("SMTP_PASSWORD").map_err(|e| AppError::missing_env("SMTP_PASSWORD", e))?;
+            transport = transport.credentials(Credentials::new(username, password));
+        }
+
+        Ok(EmailNotifier {
+            transport: transport.build(),
+            from: parse_mailbox("SMTP_FROM", &from)?,
+            to: parse_mailbox("the email channel", to)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(self.to.clone())
+            .subject(templates.title(alerts, &Plain))
+            .body(templates.bodies(alerts, &Plain))
+            .map_err(|e| AppError::notification(self.name(), e))?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| AppError::notification(self.name(), e))?;
+        Ok(())
+    }
+}
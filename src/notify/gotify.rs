@@ -0,0 +1,40 @@
+use super::{deliver, Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Plain;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+
+pub struct GotifyNotifier {
+    url: String,
+    token: String,
+}
+
+impl GotifyNotifier {
+    pub fn new(url: &str, token: &str) -> GotifyNotifier {
+        GotifyNotifier {
+            url: url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for GotifyNotifier {
+    fn name(&self) -> &'static str {
+        "gotify"
+    }
+
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        let body = json!({
+            "title": templates.title(alerts, &Plain),
+            "message": templates.bodies(alerts, &Plain),
+            "priority": 5,
+        });
+        let request = Client::new()
+            .post(format!("{}/message", self.url))
+            .header("X-Gotify-Key", &self.token)
+            .json(&body);
+        deliver(self.name(), request).await
+    }
+}
@@ -0,0 +1,368 @@
+pub mod discord;
+pub mod email;
+pub mod gotify;
+pub mod ntfy;
+pub mod slack;
+mod state;
+pub mod telegram;
+mod templates;
+pub mod webhook;
+
+pub use state::{digest_due, AlertRules, AlertState};
+pub use templates::{AlertTemplates, Templates};
+
+use crate::error::AppError;
+use crate::pricing::format_amount;
+use crate::settings::Settings;
+use crate::slug;
+use crate::tr;
+use crate::wishlist::{AlertSettings, PriceAlert};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+// Um alerta de preço sem a formatação de nenhum canal; cada backend monta a
+// sua mensagem
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Alert {
+    pub wishlist: String,
+    pub card_name: String,
+    pub version: String,
+    pub collector_number: String,
+    pub expansion_name: String,
+    pub blueprint_id: Option<u32>,
+    pub target_price: f64,
+    pub price: f64,
+    pub currency: String,
+    // Página da carta no CardTrader
+    pub link: String,
+    pub image_url: Option<String>,
+}
+
+impl From<&PriceAlert> for Alert {
+    fn from(alert: &PriceAlert) -> Self {
+        let item = &alert.item;
+        Alert {
+            wishlist: alert.wishlist.clone(),
+            card_name: item.card_name.clone(),
+            version: item.version.clone(),
+            collector_number: item.collector_number.clone(),
+            expansion_name: item.expansion_name.clone(),
+            blueprint_id: item.blueprint_id,
+            target_price: item.target_price,
+            price: alert.quote.amount,
+            currency: alert.quote.currency.clone(),
+            link: slug::card_url(&item.card_name, &item.version, &item.expansion_name),
+            image_url: item.image_url.clone(),
+        }
+    }
+}
+
+impl Alert {
+    // Variáveis dos templates de alerta
+    pub fn variables(&self) -> Vec<(&'static str, String)> {
+        let delta = self.price - self.target_price;
+        let delta_percent = if self.target_price > 0.0 {
+            format!("{:+.1}%", delta / self.target_price * 100.0)
+        } else {
+            String::new()
+        };
+        vec![
+            ("card", self.card_name.clone()),
+            ("version", self.version.clone()),
+            ("set", self.expansion_name.clone()),
+            ("collector_number", self.collector_number.clone()),
+            ("target", format_amount(self.target_price, &self.currency)),
+            ("price", format_amount(self.price, &self.currency)),
+            ("delta", format_amount(delta, &self.currency)),
+            ("delta_percent", delta_percent),
+            ("currency", self.currency.clone()),
+            ("link", self.link.clone()),
+            ("image", self.image_url.clone().unwrap_or_default()),
+            ("wishlist", self.wishlist.clone()),
+        ]
+    }
+}
+
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError>;
+}
+
+// Para onde vão os alertas de uma wishlist. Segredos do servidor (token do
+// Telegram, SMTP, ntfy) vêm do .env; aqui fica só o destino.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Channel {
+    // None usa o chat da wishlist ou TELEGRAM_CHAT_ID
+    Telegram { chat_id: Option<i64> },
+    Discord { webhook_url: String },
+    Slack { webhook_url: String },
+    Email { to: String },
+    Ntfy { server: String, topic: String },
+    Gotify { url: String, token: String },
+    Webhook { url: String },
+}
+
+fn parse_url(value: &str) -> Result<url::Url, String> {
+    url::Url::parse(value).map_err(|e| format!("invalid URL {:?}: {}", value, e))
+}
+
+impl FromStr for Channel {
+    type Err = String;
+
+    // "telegram[:chat id]", "discord:<webhook>", "slack:<webhook>",
+    // "email:<address>", "ntfy:<topic or topic URL>",
+    // "gotify:<server>?token=<app token>" ou "webhook:<url>"
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (kind, target) = value.trim().split_once(':').unwrap_or((value.trim(), ""));
+        let target = target.trim();
+        match (kind.to_lowercase().as_str(), target) {
+            ("telegram", "") => Ok(Channel::Telegram { chat_id: None }),
+            ("telegram", chat_id) => chat_id
+                .parse()
+                .map(|chat_id| Channel::Telegram {
+                    chat_id: Some(chat_id),
+                })
+                .map_err(|_| format!("invalid Telegram chat id {:?}", chat_id)),
+            (_, "") => Err(format!(
+                "{:?} needs a destination, e.g. discord:<webhook url>",
+                value
+            )),
+            ("discord", url) => Ok(Channel::Discord {
+                webhook_url: parse_url(url)?.to_string(),
+            }),
+            ("slack", url) => Ok(Channel::Slack {
+                webhook_url: parse_url(url)?.to_string(),
+            }),
+            ("email", to) if to.contains('@') => Ok(Channel::Email { to: to.to_string() }),
+            ("email", to) => Err(format!("invalid email address {:?}", to)),
+            ("ntfy", topic) if topic.contains("://") => {
+                let url = parse_url(topic)?;
+                let topic = url.path().trim_matches('/').to_string();
+                if topic.is_empty() || topic.contains('/') {
+                    return Err(format!("{:?} is not an ntfy topic URL", url.as_str()));
+                }
+                Ok(Channel::Ntfy {
+                    server: url.origin().ascii_serialization(),
+                    topic,
+                })
+            }
+            ("ntfy", topic) => Ok(Channel::Ntfy {
+                server: ntfy::DEFAULT_SERVER.to_string(),
+                topic: topic.to_string(),
+            }),
+            ("gotify", url) => {
+                let mut url = parse_url(url)?;
+                let token = url
+                    .query_pairs()
+                    .find(|(key, _)| key == "token")
+                    .map(|(_, token)| token.to_string())
+                    .ok_or_else(|| "gotify needs ?token=<app token> in the URL".to_string())?;
+                url.set_query(None);
+                Ok(Channel::Gotify {
+                    url: url.as_str().trim_end_matches('/').to_string(),
+                    token,
+                })
+            }
+            ("webhook", url) => Ok(Channel::Webhook {
+                url: parse_url(url)?.to_string(),
+            }),
+            _ => Err(format!(
+                "unknown channel {:?}, use telegram, discord, slack, email, ntfy, gotify or webhook",
+                kind
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Telegram { chat_id: None } => write!(f, "telegram"),
+            Channel::Telegram {
+                chat_id: Some(chat_id),
+            } => write!(f, "telegram:{}", chat_id),
+            Channel::Discord { webhook_url } => write!(f, "discord:{}", webhook_url),
+            Channel::Slack { webhook_url } => write!(f, "slack:{}", webhook_url),
+            Channel::Email { to } => write!(f, "email:{}", to),
+            Channel::Ntfy { server, topic } => write!(f, "ntfy:{}/{}", server, topic),
+            Channel::Gotify { url, token } => write!(f, "gotify:{}?token={}", url, token),
+            Channel::Webhook { url } => write!(f, "webhook:{}", url),
+        }
+    }
+}
+
+impl Channel {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Channel::Telegram { .. } => "telegram",
+            Channel::Discord { .. } => "discord",
+            Channel::Slack { .. } => "slack",
+            Channel::Email { .. } => "email",
+            Channel::Ntfy { .. } => "ntfy",
+            Channel::Gotify { .. } => "gotify",
+            Channel::Webhook { .. } => "webhook",
+        }
+    }
+
+    pub fn notifier(&self, settings: &AlertSettings) -> Result<Box<dyn Notifier>, AppError> {
+        Ok(match self {
+            Channel::Telegram { chat_id } => Box::new(telegram::TelegramNotifier::from_env(
+                chat_id.or(settings.telegram_chat_id),
+            )?),
+            Channel::Discord { webhook_url } => {
+                Box::new(discord::DiscordNotifier::new(webhook_url))
+            }
+            Channel::Slack { webhook_url } => Box::new(slack::SlackNotifier::new(webhook_url)),
+            Channel::Email { to } => Box::new(email::EmailNotifier::from_env(to)?),
+            Channel::Ntfy { server, topic } => Box::new(ntfy::NtfyNotifier::new(server, topic)),
+            Channel::Gotify { url, token } => Box::new(gotify::GotifyNotifier::new(url, token)),
+            Channel::Webhook { url } => Box::new(webhook::WebhookNotifier::new(url)),
+        })
+    }
+}
+
+// Canais da wishlist; sem nenhum, os padrão do settings.json; sem esses,
+// o Telegram de sempre
+pub fn channels(settings: &AlertSettings) -> Result<Vec<Channel>, AppError> {
+    if !settings.channels.is_empty() {
+        return Ok(settings.channels.clone());
+    }
+    let defaults = Settings::load()?.alert_channels;
+    if !defaults.is_empty() {
+        return Ok(defaults);
+    }
+    Ok(vec![Channel::Telegram { chat_id: None }])
+}
+
+// Envia por todos os canais da wishlist. Um canal com problema não impede os
+// outros; só é erro quando nenhum canal recebeu os alertas.
+pub async fn send_alerts(alerts: &[Alert], settings: &AlertSettings) -> Result<(), AppError> {
+    if alerts.is_empty() {
+        return Ok(());
+    }
+
+    let templates = Templates::load()?;
+    let mut first_error = None;
+    let mut delivered = false;
+    for channel in channels(settings)? {
+        let result = match channel.notifier(settings) {
+            Ok(notifier) => notifier.send(alerts, &templates).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => delivered = true,
+            Err(e) => {
+                // Sem o destino: URLs de webhook e tokens são segredos
+                eprintln!(
+                    "{}",
+                    tr!("alert.not_sent", channel = channel.kind(), error = e)
+                );
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) if !delivered => Err(e),
+        _ => Ok(()),
+    }
+}
+
+// Envia uma requisição de um backend HTTP; erros de rede e respostas de erro
+// viram erro do canal
+async fn deliver(channel: &'static str, request: reqwest::RequestBuilder) -> Result<(), AppError> {
+    request
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| AppError::notification(channel, e.without_url()))?;
+    Ok(())
+}
+
+pub(crate) fn split_message(message: &str, max_length: usize) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut current_chunk = String::new();
+
+    for line in message.lines() {
+        if !current_chunk.is_empty() && current_chunk.len() + line.len() + 1 > max_length {
+            result.push(current_chunk.clone());
+            current_chunk.clear();
+        }
+        current_chunk.push_str(line);
+        current_chunk.push('\n');
+    }
+
+    if !current_chunk.is_empty() {
+        result.push(current_chunk);
+    }
+
+    result
+}
+
+// Cada mensagem leva o título; um título longo demais é cortado pela metade
+// do limite para sobrar espaço para os alertas
+pub(crate) fn titled_messages(title: &str, bodies: &str, max_length: usize) -> Vec<String> {
+    let mut end = title.len().min(max_length / 2);
+    while !title.is_char_boundary(end) {
+        end -= 1;
+    }
+    // Um escape cortado ao meio deixaria uma barra sozinha no fim
+    let title = title[..end].trim_end_matches('\\');
+    split_message(bodies, max_length - title.len() - 2)
+        .into_iter()
+        .map(|chunk| format!("{}\n\n{}", title, chunk))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_parse_and_print_back() {
+        let specs = [
+            ("telegram", "telegram"),
+            ("telegram:-1001", "telegram:-1001"),
+            (
+                "discord:https://discord.com/api/webhooks/1/abc",
+                "discord:https://discord.com/api/webhooks/1/abc",
+            ),
+            ("email:bia@example.com", "email:bia@example.com"),
+            ("ntfy:cards", "ntfy:https://ntfy.sh/cards"),
+            (
+                "ntfy:http://localhost:8080/cards",
+                "ntfy:http://localhost:8080/cards",
+            ),
+            (
+                "gotify:https://push.example.com/?token=abc",
+                "gotify:https://push.example.com?token=abc",
+            ),
+        ];
+        for (spec, printed) in specs {
+            let channel: Channel = spec.parse().unwrap();
+            assert_eq!(channel.to_string(), printed);
+            assert_eq!(printed.parse::<Channel>().unwrap(), channel);
+        }
+        assert!("discord".parse::<Channel>().is_err());
+        assert!("email:nobody".parse::<Channel>().is_err());
+        assert!("gotify:https://push.example.com"
+            .parse::<Channel>()
+            .is_err());
+        assert!("pager:123".parse::<Channel>().is_err());
+    }
+
+    #[test]
+    fn wishlist_channels_override_the_defaults() {
+        let settings = AlertSettings {
+            channels: vec![Channel::Slack {
+                webhook_url: "https://hooks.slack.com/services/x".to_string(),
+            }],
+            ..AlertSettings::default()
+        };
+        assert_eq!(channels(&settings).unwrap(), settings.channels);
+    }
+}
@@ -0,0 +1,47 @@
+use super::{deliver, Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Plain;
+use async_trait::async_trait;
+use dotenv::dotenv;
+use reqwest::Client;
+use serde_json::json;
+use std::env;
+
+pub const DEFAULT_SERVER: &str = "https://ntfy.sh";
+
+pub struct NtfyNotifier {
+    server: String,
+    topic: String,
+}
+
+impl NtfyNotifier {
+    pub fn new(server: &str, topic: &str) -> NtfyNotifier {
+        NtfyNotifier {
+            server: server.trim_end_matches('/').to_string(),
+            topic: topic.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for NtfyNotifier {
+    fn name(&self) -> &'static str {
+        "ntfy"
+    }
+
+    // Publicação em JSON na raiz do servidor; NTFY_TOKEN para tópicos protegidos
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        dotenv().ok();
+        let body = json!({
+            "topic": self.topic,
+            "title": templates.title(alerts, &Plain),
+            "message": templates.bodies(alerts, &Plain),
+            "tags": ["moneybag"],
+        });
+        let mut request = Client::new().post(&self.server).json(&body);
+        if let Ok(token) = env::var("NTFY_TOKEN") {
+            request = request.bearer_auth(token);
+        }
+        deliver(self.name(), request).await
+    }
+}
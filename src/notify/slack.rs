@@ -0,0 +1,53 @@
+use super::{deliver, Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Markup;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+
+pub struct SlackNotifier {
+    webhook_url: String,
+}
+
+impl SlackNotifier {
+    pub fn new(webhook_url: &str) -> SlackNotifier {
+        SlackNotifier {
+            webhook_url: webhook_url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for SlackNotifier {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    // Webhooks do Slack aceitam mensagens longas, então vai tudo de uma vez
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        let text = format!(
+            "{}\n\n{}",
+            templates.title(alerts, &Mrkdwn),
+            templates.bodies(alerts, &Mrkdwn)
+        );
+        let request = Client::new()
+            .post(&self.webhook_url)
+            .json(&json!({ "text": text }));
+        deliver(self.name(), request).await
+    }
+}
+
+struct Mrkdwn;
+
+impl Markup for Mrkdwn {
+    // O mrkdwn do Slack só exige escapar estes três
+    fn escape(&self, text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("*{}*", text)
+    }
+}
@@ -0,0 +1,265 @@
+use super::Alert;
+use crate::wishlist::{PriceAlert, WishlistItem};
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+fn default_cooldown_hours() -> u32 {
+    24
+}
+
+fn default_step_percent() -> f64 {
+    5.0
+}
+
+fn default_digest_hour() -> u32 {
+    9
+}
+
+// Quando repetir o alerta de uma carta que continua abaixo do preço desejado
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AlertRules {
+    // Horas até avisar de novo pelo mesmo preço; 0 avisa em toda verificação
+    #[serde(default = "default_cooldown_hours")]
+    pub cooldown_hours: u32,
+    // Queda, em % do último preço avisado, que avisa antes do cooldown
+    #[serde(default = "default_step_percent")]
+    pub step_percent: f64,
+    // Junta os alertas em uma mensagem por dia, a partir de digest_hour
+    #[serde(default)]
+    pub digest: bool,
+    // Hora local do resumo diário
+    #[serde(default = "default_digest_hour")]
+    pub digest_hour: u32,
+}
+
+impl Default for AlertRules {
+    fn default() -> Self {
+        AlertRules {
+            cooldown_hours: default_cooldown_hours(),
+            step_percent: default_step_percent(),
+            digest: false,
+            digest_hour: default_digest_hour(),
+        }
+    }
+}
+
+// Último alerta enviado de uma carta
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SentAlert {
+    pub key: String,
+    pub price: f64,
+    pub currency: String,
+    pub sent_at: DateTime<Utc>,
+}
+
+// Alerta esperando o próximo resumo diário
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct QueuedAlert {
+    pub key: String,
+    pub alert: Alert,
+}
+
+// O que já foi avisado em uma wishlist, para não repetir o mesmo alerta a
+// cada verificação
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AlertState {
+    pub wishlist: String,
+    #[serde(default)]
+    pub sent: Vec<SentAlert>,
+    #[serde(default)]
+    pub pending: Vec<QueuedAlert>,
+    #[serde(default)]
+    pub last_digest_at: Option<DateTime<Utc>>,
+}
+
+impl AlertState {
+    pub fn new(wishlist: &str) -> Self {
+        AlertState {
+            wishlist: wishlist.to_string(),
+            ..AlertState::default()
+        }
+    }
+
+    // Acompanha a wishlist renomeada, inclusive nos alertas da fila
+    pub fn rename(&mut self, to: &str) {
+        self.wishlist = to.to_string();
+        for queued in &mut self.pending {
+            queued.alert.wishlist = to.to_string();
+        }
+    }
+
+    // Avisa se a carta ainda não foi avisada, se o preço caiu pelo menos
+    // step_percent desde o último aviso ou se o cooldown já passou
+    pub fn should_notify(
+        &self,
+        key: &str,
+        price: f64,
+        currency: &str,
+        now: DateTime<Utc>,
+        rules: &AlertRules,
+    ) -> bool {
+        let Some(sent) = self.sent.iter().find(|sent| sent.key == key) else {
+            return true;
+        };
+        if sent.currency != currency {
+            return true;
+        }
+        let step = sent.price * rules.step_percent.max(0.0) / 100.0;
+        if price < sent.price && sent.price - price >= step {
+            return true;
+        }
+        now - sent.sent_at >= TimeDelta::hours(rules.cooldown_hours.into())
+    }
+
+    fn record(&mut self, key: &str, price: f64, currency: &str, now: DateTime<Utc>) {
+        let sent = SentAlert {
+            key: key.to_string(),
+            price,
+            currency: currency.to_string(),
+            sent_at: now,
+        };
+        match self.sent.iter_mut().find(|sent| sent.key == key) {
+            Some(existing) => *existing = sent,
+            None => self.sent.push(sent),
+        }
+    }
+
+    // Separa os alertas que devem sair dos repetidos e já os registra como
+    // avisados; devolve também quantos foram suprimidos
+    pub fn select(
+        &mut self,
+        alerts: &[PriceAlert],
+        now: DateTime<Utc>,
+        rules: &AlertRules,
+    ) -> (Vec<(String, Alert)>, usize) {
+        let mut selected = Vec::new();
+        let mut suppressed = 0;
+        for alert in alerts {
+            let key = alert.item.key();
+            let quote = &alert.quote;
+            if self.should_notify(&key, quote.amount, &quote.currency, now, rules) {
+                self.record(&key, quote.amount, &quote.currency, now);
+                selected.push((key, Alert::from(alert)));
+            } else {
+                suppressed += 1;
+            }
+        }
+        (selected, suppressed)
+    }
+
+    // Esquece as cartas que saíram da wishlist ou voltaram a ficar acima do
+    // preço desejado: se caírem de novo, é um alerta novo. Falhas de consulta
+    // mantêm o último preço e não apagam nada.
+    pub fn forget_recovered(&mut self, items: &[WishlistItem]) {
+        let below_target = |key: &str| {
+            items.iter().any(|item| {
+                item.key() == key
+                    && item
+                        .last_price
+                        .is_some_and(|price| item.is_at_or_below_target(price))
+            })
+        };
+        self.sent.retain(|sent| below_target(&sent.key));
+        self.pending.retain(|queued| below_target(&queued.key));
+    }
+
+    // Guarda para o resumo; uma carta já na fila fica só com o alerta novo
+    pub fn enqueue(&mut self, alerts: Vec<(String, Alert)>) {
+        for (key, alert) in alerts {
+            self.pending.retain(|queued| queued.key != key);
+            self.pending.push(QueuedAlert { key, alert });
+        }
+    }
+}
+
+// O resumo sai uma vez por dia, na primeira verificação depois de `hour`
+// (no fuso de `now`)
+pub fn digest_due<Tz: TimeZone>(
+    last: Option<DateTime<Utc>>,
+    now: &DateTime<Tz>,
+    hour: u32,
+) -> bool {
+    let today = now.date_naive();
+    let Some(start) = today
+        .and_hms_opt(hour.min(23), 0, 0)
+        .and_then(|start| now.timezone().from_local_datetime(&start).earliest())
+    else {
+        return false;
+    };
+    if *now < start {
+        return false;
+    }
+    match last {
+        Some(last) => last.with_timezone(&now.timezone()) < start,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::blueprint::BlueprintData;
+    use crate::pricing::PriceQuote;
+
+    fn alert(price: f64) -> PriceAlert {
+        let blueprint = BlueprintData {
+            blueprint_id: 11,
+            card_name: "Sol Ring".to_string(),
+            expansion_name: "Alpha".to_string(),
+            ..BlueprintData::default()
+        };
+        let mut item = WishlistItem::from_blueprint(&blueprint, 20.0);
+        let quote = PriceQuote::new(price, "EUR");
+        item.record_quote(&quote);
+        PriceAlert {
+            wishlist: "default".to_string(),
+            item,
+            quote,
+        }
+    }
+
+    #[test]
+    fn repeated_alerts_wait_for_a_lower_price_or_the_cooldown() {
+        let rules = AlertRules::default();
+        let mut state = AlertState::new("default");
+        let now = Utc::now();
+
+        let (selected, suppressed) = state.select(&[alert(15.0)], now, &rules);
+        assert_eq!((selected.len(), suppressed), (1, 0));
+
+        // Mesmo preço e queda menor que 5%: suprimidos
+        let (selected, suppressed) = state.select(&[alert(15.0)], now, &rules);
+        assert_eq!((selected.len(), suppressed), (0, 1));
+        let (selected, _) = state.select(&[alert(14.5)], now, &rules);
+        assert!(selected.is_empty());
+
+        // Queda de 5% sobre o último aviso
+        let (selected, _) = state.select(&[alert(14.25)], now, &rules);
+        assert_eq!(selected.len(), 1);
+
+        let later = now + TimeDelta::hours(24);
+        let (selected, _) = state.select(&[alert(14.25)], later, &rules);
+        assert_eq!(selected.len(), 1);
+
+        // Acima do alvo, a carta é esquecida e o próximo alerta sai na hora
+        let mut recovered = alert(25.0).item;
+        recovered.record_quote(&PriceQuote::new(25.0, "EUR"));
+        state.forget_recovered(&[recovered]);
+        assert!(state.sent.is_empty());
+        let (selected, _) = state.select(&[alert(14.25)], later, &rules);
+        assert_eq!(selected.len(), 1);
+    }
+
+    #[test]
+    fn the_digest_goes_out_once_a_day_after_its_hour() {
+        let at = |day: u32, hour: u32| Utc.with_ymd_and_hms(2024, 5, day, hour, 30, 0).unwrap();
+
+        assert!(!digest_due(None, &at(1, 8), 9));
+        assert!(digest_due(None, &at(1, 9), 9));
+        assert!(!digest_due(Some(at(1, 9)), &at(1, 20), 9));
+        assert!(!digest_due(Some(at(1, 9)), &at(2, 8), 9));
+        assert!(digest_due(Some(at(1, 9)), &at(2, 10), 9));
+        // Sem rodar há dias, sai na primeira verificação
+        assert!(digest_due(Some(at(1, 9)), &at(5, 23), 9));
+    }
+}
@@ -0,0 +1,167 @@
+use super::{titled_messages, Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Markup;
+use async_trait::async_trait;
+use dotenv::dotenv;
+use reqwest::{Client, Url};
+use std::env;
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, ParseMode};
+use teloxide::Bot;
+
+// Limite de caracteres de uma mensagem do Telegram
+const MAX_LENGTH: usize = 4096;
+
+// TELEGRAM_API_URL troca o servidor da Bot API, por exemplo por um servidor
+// falso local nos testes
+pub fn bot(token: &str) -> Result<Bot, AppError> {
+    dotenv().ok();
+    let client = Client::builder().build()?;
+    let bot = Bot::with_client(token, client);
+    match env::var("TELEGRAM_API_URL") {
+        Ok(url) => {
+            let url = Url::parse(&url).map_err(|e| {
+                AppError::Config(format!("TELEGRAM_API_URL is not a valid URL: {}", e))
+            })?;
+            Ok(bot.set_api_url(url))
+        }
+        Err(_) => Ok(bot),
+    }
+}
+
+pub async fn send_message(token: &str, chat_id: ChatId, message: &str) -> Result<(), AppError> {
+    let bot = bot(token)?.parse_mode(ParseMode::MarkdownV2);
+    bot.send_message(chat_id, message).send().await?;
+    Ok(())
+}
+
+pub struct TelegramNotifier {
+    token: String,
+    chat_id: ChatId,
+}
+
+impl TelegramNotifier {
+    // Sem chat na wishlist, os alertas vão para TELEGRAM_CHAT_ID
+    pub fn from_env(chat_id: Option<i64>) -> Result<TelegramNotifier, AppError> {
+        dotenv().ok();
+        let token =
+            env::var("TELEGRAM_TOKEN").map_err(|e| AppError::missing_env("TELEGRAM_TOKEN", e))?;
+        let chat_id = match chat_id {
+            Some(chat_id) => chat_id,
+            None => env::var("TELEGRAM_CHAT_ID")
+                .map_err(|e| AppError::missing_env("TELEGRAM_CHAT_ID", e))?
+                .parse()
+                .map_err(|_| {
+                    AppError::Config("TELEGRAM_CHAT_ID must be a valid i64".to_string())
+                })?,
+        };
+        Ok(TelegramNotifier {
+            token,
+            chat_id: ChatId(chat_id),
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for TelegramNotifier {
+    fn name(&self) -> &'static str {
+        "telegram"
+    }
+
+    // Mensagens agrupadas abaixo do limite do Telegram, cada uma com o título
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        for message in messages(alerts, templates) {
+            send_message(&self.token, self.chat_id, &message).await?;
+        }
+        Ok(())
+    }
+}
+
+// Um título que vai ser cortado perde o negrito: um * sem par faria o
+// Telegram recusar a mensagem
+fn messages(alerts: &[Alert], templates: &Templates) -> Vec<String> {
+    let mut title = templates.title(alerts, &MarkdownV2);
+    if title.len() > MAX_LENGTH / 2 {
+        title = templates.title(alerts, &EscapedOnly);
+    }
+    titled_messages(&title, &templates.bodies(alerts, &MarkdownV2), MAX_LENGTH)
+}
+
+struct MarkdownV2;
+
+struct EscapedOnly;
+
+impl Markup for EscapedOnly {
+    fn escape(&self, text: &str) -> String {
+        escape_markdown(text)
+    }
+}
+
+impl Markup for MarkdownV2 {
+    fn escape(&self, text: &str) -> String {
+        escape_markdown(text)
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("*{}*", text)
+    }
+}
+
+// Função auxiliar para escapar caracteres especiais no MarkdownV2
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '_' | '*' | '[' | ']' | '(' | ')' | '~' | '`' | '>' | '#' | '+' | '-' | '=' | '|'
+            | '{' | '}' | '.' | '!' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+    use crate::notify::AlertTemplates;
+
+    #[test]
+    fn long_titles_still_fit_in_a_message() {
+        let custom = AlertTemplates {
+            title: Some(format!(
+                "{{{{#bold}}}}{}{{{{/bold}}}}",
+                "Prices. ".repeat(1000)
+            )),
+            body: None,
+        };
+        let templates = Templates::new(Locale::En, &custom).unwrap();
+        let alert = Alert {
+            wishlist: "edh".to_string(),
+            card_name: "Sol Ring".to_string(),
+            version: String::new(),
+            collector_number: "1".to_string(),
+            expansion_name: "Alpha".to_string(),
+            blueprint_id: Some(11),
+            target_price: 20.0,
+            price: 15.0,
+            currency: "EUR".to_string(),
+            link: "https://www.cardtrader.com/cards/sol-ring-alpha".to_string(),
+            image_url: None,
+        };
+        let alerts = vec![alert; 100];
+
+        let messages = messages(&alerts, &templates);
+
+        assert!(messages.len() > 1);
+        for message in &messages {
+            assert!(message.len() <= MAX_LENGTH);
+            assert!(!message.starts_with('*'));
+            assert!(!message.split("\n\n").next().unwrap().ends_with('\\'));
+        }
+        assert_eq!(messages.concat().matches("Sol Ring").count(), 100);
+    }
+}
@@ -0,0 +1,158 @@
+use super::Alert;
+use crate::error::AppError;
+use crate::i18n::{self, Locale};
+use crate::settings::Settings;
+use crate::template::{Markup, Template};
+use crate::wishlist::DEFAULT_WISHLIST;
+use serde::{Deserialize, Serialize};
+
+pub const TITLE_VARIABLES: [&str; 3] = ["list", "wishlist", "count"];
+pub const BODY_VARIABLES: [&str; 12] = [
+    "card",
+    "version",
+    "set",
+    "collector_number",
+    "target",
+    "price",
+    "delta",
+    "delta_percent",
+    "currency",
+    "link",
+    "image",
+    "wishlist",
+];
+
+// Templates escritos pelo usuário, no settings.json; None usa o do idioma
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AlertTemplates {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+// Templates prontos para os canais: o título de cada mensagem e o texto de
+// cada alerta
+pub struct Templates {
+    title: Template,
+    body: Template,
+}
+
+fn parse(source: &str, known: &[&str]) -> Result<Template, AppError> {
+    let template = Template::parse(source)
+        .map_err(|e| AppError::Config(format!("invalid alert template: {}", e)))?;
+    template
+        .check_variables(known)
+        .map_err(|e| AppError::Config(format!("invalid alert template: {}", e)))?;
+    Ok(template)
+}
+
+impl Templates {
+    pub fn new(locale: Locale, custom: &AlertTemplates) -> Result<Templates, AppError> {
+        let title = custom
+            .title
+            .as_deref()
+            .unwrap_or(i18n::message(locale, "alert.title"));
+        let body = custom
+            .body
+            .as_deref()
+            .unwrap_or(i18n::message(locale, "alert.body"));
+        Ok(Templates {
+            title: parse(title, &TITLE_VARIABLES)?,
+            body: parse(body, &BODY_VARIABLES)?,
+        })
+    }
+
+    pub fn load() -> Result<Templates, AppError> {
+        let settings = Settings::load()?;
+        Templates::new(settings.locale, &settings.alert_templates)
+    }
+
+    // Os alertas de uma chamada são sempre da mesma wishlist; "list" só é
+    // preenchida fora da padrão
+    pub fn title(&self, alerts: &[Alert], markup: &dyn Markup) -> String {
+        let wishlist = alerts
+            .first()
+            .map(|alert| alert.wishlist.clone())
+            .unwrap_or_default();
+        let list = if wishlist == DEFAULT_WISHLIST {
+            String::new()
+        } else {
+            wishlist.clone()
+        };
+        let vars = [
+            ("list", list),
+            ("wishlist", wishlist),
+            ("count", alerts.len().to_string()),
+        ];
+        self.title.render(&vars, markup)
+    }
+
+    pub fn body(&self, alert: &Alert, markup: &dyn Markup) -> String {
+        self.body.render(&alert.variables(), markup)
+    }
+
+    // Todos os alertas, separados por uma linha em branco
+    pub fn bodies(&self, alerts: &[Alert], markup: &dyn Markup) -> String {
+        alerts
+            .iter()
+            .map(|alert| self.body(alert, markup))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::Plain;
+
+    fn alert() -> Alert {
+        Alert {
+            wishlist: "edh".to_string(),
+            card_name: "Sol Ring".to_string(),
+            version: String::new(),
+            collector_number: "1".to_string(),
+            expansion_name: "Alpha".to_string(),
+            blueprint_id: Some(11),
+            target_price: 20.0,
+            price: 15.0,
+            currency: "EUR".to_string(),
+            link: "https://www.cardtrader.com/cards/sol-ring-alpha".to_string(),
+            image_url: None,
+        }
+    }
+
+    #[test]
+    fn alerts_use_the_locale_or_the_custom_templates() {
+        let templates = Templates::new(Locale::PtBr, &AlertTemplates::default()).unwrap();
+        assert_eq!(
+            templates.title(&[alert()], &Plain),
+            "Alerta de Preço Baixo! (edh)"
+        );
+        assert_eq!(
+            templates.body(&alert(), &Plain),
+            "Sol Ring (1) [Alpha]\nPreço Desejado: € 20.00\nPreço Atual: € 15.00"
+        );
+
+        let custom = AlertTemplates {
+            title: Some("{{count}} deal(s)".to_string()),
+            body: Some(
+                "{{card}} {{delta}} ({{delta_percent}}){{#if image}} {{image}}{{/if}} {{link}}"
+                    .to_string(),
+            ),
+        };
+        let templates = Templates::new(Locale::En, &custom).unwrap();
+        assert_eq!(templates.title(&[alert()], &Plain), "1 deal(s)");
+        assert_eq!(
+            templates.body(&alert(), &Plain),
+            "Sol Ring € -5.00 (-25.0%) https://www.cardtrader.com/cards/sol-ring-alpha"
+        );
+
+        let unknown = AlertTemplates {
+            body: Some("{{card_name}}".to_string()),
+            ..AlertTemplates::default()
+        };
+        assert!(Templates::new(Locale::En, &unknown).is_err());
+    }
+}
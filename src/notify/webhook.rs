@@ -0,0 +1,37 @@
+use super::{deliver, Alert, Notifier, Templates};
+use crate::error::AppError;
+use crate::template::Plain;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+
+// Webhook genérico: recebe os alertas em JSON, além do texto sem formatação,
+// para quem quiser integrar com outra coisa
+pub struct WebhookNotifier {
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str) -> WebhookNotifier {
+        WebhookNotifier {
+            url: url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn send(&self, alerts: &[Alert], templates: &Templates) -> Result<(), AppError> {
+        let body = json!({
+            "title": templates.title(alerts, &Plain),
+            "message": templates.bodies(alerts, &Plain),
+            "wishlist": alerts.first().map(|alert| alert.wishlist.as_str()),
+            "alerts": alerts,
+        });
+        deliver(self.name(), Client::new().post(&self.url).json(&body)).await
+    }
+}
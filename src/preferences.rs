@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+// Condições do CardTrader, da melhor para a pior
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Condition {
+    #[serde(rename = "NM")]
+    NearMint,
+    #[serde(rename = "SP")]
+    SlightlyPlayed,
+    #[serde(rename = "MP")]
+    ModeratelyPlayed,
+    #[serde(rename = "PL")]
+    Played,
+    #[serde(rename = "HP")]
+    HeavilyPlayed,
+    #[serde(rename = "PO")]
+    Poor,
+}
+
+impl Condition {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Condition::NearMint => "NM",
+            Condition::SlightlyPlayed => "SP",
+            Condition::ModeratelyPlayed => "MP",
+            Condition::Played => "PL",
+            Condition::HeavilyPlayed => "HP",
+            Condition::Poor => "PO",
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    // Aceita o código ("NM") ou o nome usado nos anúncios ("Near Mint")
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "m" | "mint" | "nm" | "near mint" => Ok(Condition::NearMint),
+            "sp" | "slightly played" => Ok(Condition::SlightlyPlayed),
+            "mp" | "moderately played" => Ok(Condition::ModeratelyPlayed),
+            "pl" | "played" => Ok(Condition::Played),
+            "hp" | "heavily played" => Ok(Condition::HeavilyPlayed),
+            "po" | "poor" => Ok(Condition::Poor),
+            _ => Err(format!(
+                "unknown condition {:?}, use NM, SP, MP, PL, HP or PO",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum FoilPreference {
+    #[default]
+    Any,
+    Foil,
+    NonFoil,
+}
+
+impl FromStr for FoilPreference {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "any" => Ok(FoilPreference::Any),
+            "foil" => Ok(FoilPreference::Foil),
+            "non-foil" | "non_foil" | "nonfoil" => Ok(FoilPreference::NonFoil),
+            _ => Err(format!(
+                "unknown foil preference {:?}, use any, foil or non-foil",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for FoilPreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FoilPreference::Any => "any",
+            FoilPreference::Foil => "foil",
+            FoilPreference::NonFoil => "non-foil",
+        })
+    }
+}
+
+// Quais anúncios servem para um item. O padrão aceita qualquer anúncio.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default, Debug)]
+pub struct Preferences {
+    #[serde(default)]
+    pub min_condition: Option<Condition>,
+    // Vazio aceita qualquer idioma
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub foil: FoilPreference,
+    // Só vendedores que enviam pelo CardTrader Zero
+    #[serde(default)]
+    pub zero_only: bool,
+}
+
+impl Preferences {
+    pub fn is_any(&self) -> bool {
+        *self == Preferences::default()
+    }
+
+    // Um anúncio sem a informação que a preferência exige é recusado
+    pub fn accepts(
+        &self,
+        condition: Option<&str>,
+        language: Option<&str>,
+        foil: Option<bool>,
+        zero: Option<bool>,
+    ) -> bool {
+        let condition_ok = self.min_condition.is_none_or(|min| {
+            condition
+                .and_then(|condition| condition.parse::<Condition>().ok())
+                .is_some_and(|condition| condition <= min)
+        });
+        let language_ok = self.languages.is_empty()
+            || language.is_some_and(|language| {
+                self.languages
+                    .iter()
+                    .any(|accepted| accepted.eq_ignore_ascii_case(language))
+            });
+        let foil_ok = match self.foil {
+            FoilPreference::Any => true,
+            FoilPreference::Foil => foil == Some(true),
+            // Jogos sem foil não informam a propriedade
+            FoilPreference::NonFoil => foil != Some(true),
+        };
+        let zero_ok = !self.zero_only || zero == Some(true);
+        condition_ok && language_ok && foil_ok && zero_ok
+    }
+}
+
+impl fmt::Display for Preferences {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(condition) = self.min_condition {
+            parts.push(format!("{} or better", condition));
+        }
+        if !self.languages.is_empty() {
+            parts.push(self.languages.join("/"));
+        }
+        if self.foil != FoilPreference::Any {
+            parts.push(self.foil.to_string());
+        }
+        if self.zero_only {
+            parts.push("CardTrader Zero".to_string());
+        }
+        if parts.is_empty() {
+            f.write_str("any listing")
+        } else {
+            f.write_str(&parts.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listings_are_filtered_by_every_preference() {
+        let preferences = Preferences {
+            min_condition: Some(Condition::SlightlyPlayed),
+            languages: vec!["en".to_string(), "it".to_string()],
+            foil: FoilPreference::NonFoil,
+            zero_only: true,
+        };
+
+        assert!(preferences.accepts(Some("Near Mint"), Some("EN"), Some(false), Some(true)));
+        assert!(preferences.accepts(Some("Slightly Played"), Some("it"), None, Some(true)));
+        assert!(!preferences.accepts(Some("Moderately Played"), Some("en"), None, Some(true)));
+        assert!(!preferences.accepts(Some("Near Mint"), Some("jp"), None, Some(true)));
+        assert!(!preferences.accepts(Some("Near Mint"), Some("en"), Some(true), Some(true)));
+        assert!(!preferences.accepts(Some("Near Mint"), Some("en"), None, Some(false)));
+        assert!(!preferences.accepts(None, Some("en"), None, Some(true)));
+        assert!(Preferences::default().accepts(None, None, None, None));
+    }
+
+    #[test]
+    fn conditions_parse_codes_and_listing_names() {
+        assert_eq!("pl".parse::<Condition>(), Ok(Condition::Played));
+        assert_eq!("Mint".parse::<Condition>(), Ok(Condition::NearMint));
+        assert!("good".parse::<Condition>().is_err());
+        assert_eq!("non-foil".parse(), Ok(FoilPreference::NonFoil));
+    }
+}
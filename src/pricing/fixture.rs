@@ -0,0 +1,56 @@
+use super::{CardRef, PriceOutcome, PriceQuote, PriceSource};
+use crate::error::AppError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+
+// Fonte de preços baseada em arquivo, para testar a lógica de alertas offline.
+// O arquivo é um objeto JSON indexado por CardRef::key (blueprint_id ou
+// "nome|expansão|versão"); um valor null simula uma carta sem anúncios e uma
+// chave ausente simula uma falha na consulta.
+pub struct FixturePriceSource {
+    quotes: HashMap<String, Option<PriceQuote>>,
+}
+
+impl FixturePriceSource {
+    pub fn new(quotes: HashMap<String, Option<PriceQuote>>) -> Self {
+        FixturePriceSource { quotes }
+    }
+
+    pub fn from_file(file_path: &str) -> Result<Self, AppError> {
+        let file = File::open(file_path)?;
+        let reader = BufReader::new(file);
+        let quotes = serde_json::from_reader(reader)?;
+        Ok(FixturePriceSource::new(quotes))
+    }
+}
+
+#[async_trait]
+impl PriceSource for FixturePriceSource {
+    fn name(&self) -> &'static str {
+        "fixture"
+    }
+
+    async fn fetch_quote(&self, card: &CardRef) -> Result<PriceOutcome, AppError> {
+        match self.quotes.get(&card.key()) {
+            // A cotação do arquivo faz o papel do anúncio mais barato
+            Some(Some(quote))
+                if card.preferences.accepts(
+                    quote.condition.as_deref(),
+                    quote.language.as_deref(),
+                    quote.foil,
+                    quote.zero,
+                ) =>
+            {
+                Ok(PriceOutcome::Quote(quote.clone()))
+            }
+            Some(Some(_)) => Ok(PriceOutcome::NoListings),
+            Some(None) => Ok(PriceOutcome::NoListings),
+            None => Err(AppError::CardNotFound(format!(
+                "no fixture price for {}",
+                card.key()
+            ))),
+        }
+    }
+}
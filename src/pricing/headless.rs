@@ -0,0 +1,119 @@
+use super::{CardRef, PriceOutcome, PriceQuote, PriceSource};
+use crate::error::AppError;
+use crate::slug;
+use async_trait::async_trait;
+use headless_chrome::{Browser, LaunchOptionsBuilder};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OnceCell, Semaphore};
+use tokio::task;
+
+const MAX_CONCURRENT_REQUESTS: usize = 15; // Limite de abas abertas em paralelo
+const MAX_RETRIES: usize = 2; // Número máximo de tentativas
+
+// Abre a página da carta em um Chrome headless e lê div.price-box__price.
+// O navegador é iniciado na primeira consulta e reutilizado pelas seguintes.
+pub struct HeadlessChromePriceSource {
+    browser: OnceCell<Browser>,
+    semaphore: Arc<Semaphore>,
+}
+
+impl HeadlessChromePriceSource {
+    pub fn new() -> Self {
+        HeadlessChromePriceSource {
+            browser: OnceCell::new(),
+            semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS)),
+        }
+    }
+
+    async fn browser(&self) -> Result<Browser, AppError> {
+        self.browser
+            .get_or_try_init(|| async {
+                Browser::new(
+                    LaunchOptionsBuilder::default()
+                        .headless(true)
+                        .build()
+                        .unwrap(),
+                )
+                .map_err(AppError::browser)
+            })
+            .await
+            .cloned()
+    }
+}
+
+impl Default for HeadlessChromePriceSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn scrape_price(browser: &Browser, url: &str) -> Result<PriceOutcome, AppError> {
+    let mut last_error = AppError::browser(format!("failed to load {}", url));
+
+    for _ in 0..MAX_RETRIES {
+        let tab = match browser.new_tab() {
+            Ok(tab) => tab,
+            Err(e) => {
+                last_error = AppError::browser(e);
+                continue;
+            }
+        };
+        if let Err(e) = tab
+            .navigate_to(url)
+            .and_then(|tab| tab.wait_until_navigated())
+        {
+            last_error = AppError::browser(e);
+            let _ = tab.close(false);
+            continue;
+        }
+        std::thread::sleep(Duration::from_secs(5)); // Espera adicional para garantir que a página carregue completamente
+
+        // A página carregou mas não tem caixa de preço: carta sem anúncios
+        let price_text = match tab.find_element("div.price-box__price") {
+            Ok(price_element) => price_element.get_inner_text(),
+            Err(_) => {
+                let _ = tab.close(false);
+                return Ok(PriceOutcome::NoListings);
+            }
+        };
+        let _ = tab.close(false);
+
+        let price_text = price_text.map_err(AppError::browser)?;
+        return parse_price_text(&price_text)
+            .map(|amount| PriceOutcome::Quote(PriceQuote::new(amount, "BRL")));
+    }
+
+    Err(last_error)
+}
+
+pub(super) fn parse_price_text(price_text: &str) -> Result<f64, AppError> {
+    price_text
+        .trim()
+        .replace("R$", "")
+        .replace(" ", "")
+        .replace(",", ".")
+        .parse::<f64>()
+        .map_err(|_| AppError::browser(format!("could not parse price {:?}", price_text)))
+}
+
+#[async_trait]
+impl PriceSource for HeadlessChromePriceSource {
+    fn name(&self) -> &'static str {
+        "headless_chrome"
+    }
+
+    async fn fetch_quote(&self, card: &CardRef) -> Result<PriceOutcome, AppError> {
+        super::check_scrape_support(card)?;
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("semaphore is never closed");
+        let browser = self.browser().await?;
+        let url = slug::card_url(&card.card_name, &card.version, &card.expansion_name);
+
+        // headless_chrome é bloqueante, então roda fora do executor
+        task::spawn_blocking(move || scrape_price(&browser, &url)).await?
+    }
+}
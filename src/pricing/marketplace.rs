@@ -0,0 +1,194 @@
+use super::{CardRef, PriceOutcome, PriceQuote, PriceSource};
+use crate::api::marketplace::MarketplaceProduct;
+use crate::api::CardTraderClient;
+use crate::error::AppError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// Consulta o endpoint /marketplace/products e usa o anúncio mais barato.
+pub struct MarketplacePriceSource {
+    api: CardTraderClient,
+    fallback: Option<Arc<dyn PriceSource>>,
+}
+
+impl MarketplacePriceSource {
+    pub fn new(api: CardTraderClient) -> Self {
+        MarketplacePriceSource {
+            api,
+            fallback: None,
+        }
+    }
+
+    // Fonte usada para itens antigos da wishlist que não têm blueprint_id
+    pub fn with_fallback(mut self, fallback: Arc<dyn PriceSource>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+#[async_trait]
+impl PriceSource for MarketplacePriceSource {
+    fn name(&self) -> &'static str {
+        "marketplace_api"
+    }
+
+    async fn fetch_quote(&self, card: &CardRef) -> Result<PriceOutcome, AppError> {
+        let blueprint_id = match (card.blueprint_id, &self.fallback) {
+            (Some(blueprint_id), _) => blueprint_id,
+            (None, Some(fallback)) => return fallback.fetch_quote(card).await,
+            (None, None) => {
+                return Err(AppError::CardNotFound(format!(
+                    "{} has no blueprint id",
+                    card.card_name
+                )))
+            }
+        };
+
+        let products = self.api.marketplace_products(blueprint_id).await?;
+        Ok(select_listings(&products, card))
+    }
+}
+
+// Anúncios aceitos pelas preferências do item, do mais barato ao mais caro.
+// O preço é a média por cópia das `quantity` cópias mais baratas; se não há
+// cópias suficientes à venda, não há preço para a quantidade pedida.
+fn select_listings(products: &[MarketplaceProduct], card: &CardRef) -> PriceOutcome {
+    let mut available: Vec<&MarketplaceProduct> = products
+        .iter()
+        .filter(|product| product.quantity > 0 && !product.on_vacation)
+        .filter(|product| {
+            card.preferences.accepts(
+                product.property_str("condition").as_deref(),
+                product.property_str("language").as_deref(),
+                product.property_bool("foil"),
+                Some(product.user.can_sell_via_hub),
+            )
+        })
+        .collect();
+    available.sort_by_key(|product| product.price.cents);
+
+    let cheapest = match available.first() {
+        Some(product) if card.quantity > 0 => *product,
+        _ => return PriceOutcome::NoListings,
+    };
+
+    let mut copies = 0;
+    let mut total_cents = 0;
+    for product in &available {
+        let take = product.quantity.min(card.quantity - copies);
+        copies += take;
+        total_cents += product.price.cents * take as i64;
+        if copies == card.quantity {
+            break;
+        }
+    }
+    if copies < card.quantity {
+        return PriceOutcome::NoListings;
+    }
+
+    let mut quote = PriceQuote::new(
+        total_cents as f64 / copies as f64 / 100.0,
+        &cheapest.price.currency,
+    );
+    quote.listings = Some(available.len() as u32);
+    quote.seller = Some(cheapest.user.username.clone());
+    quote.condition = cheapest.property_str("condition");
+    quote.language = cheapest.property_str("language");
+    quote.foil = cheapest.property_bool("foil");
+    quote.zero = Some(cheapest.user.can_sell_via_hub);
+    PriceOutcome::Quote(quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preferences::{Condition, Preferences};
+
+    fn product(cents: i64, quantity: u32, condition: &str, zero: bool) -> MarketplaceProduct {
+        serde_json::from_value(serde_json::json!({
+            "id": cents,
+            "blueprint_id": 1,
+            "name_en": "Lightning Bolt",
+            "quantity": quantity,
+            "price": {"cents": cents, "currency": "EUR"},
+            "description": null,
+            "properties_hash": {"condition": condition, "mtg_language": "en", "mtg_foil": false},
+            "expansion": {"id": 1, "code": "m11", "name_en": "Magic 2011"},
+            "user": {"id": 1, "username": "seller", "can_sell_via_hub": zero,
+                     "country_code": "IT", "user_type": "normal"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn price_follows_preferences_and_quantity() {
+        let products = vec![
+            product(100, 1, "Played", true),
+            product(200, 1, "Near Mint", false),
+            product(300, 1, "Near Mint", true),
+            product(500, 3, "Slightly Played", true),
+        ];
+        let card = CardRef {
+            card_name: "Lightning Bolt".to_string(),
+            expansion_name: "Magic 2011".to_string(),
+            version: String::new(),
+            blueprint_id: Some(1),
+            quantity: 1,
+            preferences: Preferences::default(),
+        };
+
+        let amount = |card: &CardRef| match select_listings(&products, card) {
+            PriceOutcome::Quote(quote) => Some(quote.amount),
+            PriceOutcome::NoListings => None,
+        };
+        assert_eq!(amount(&card), Some(1.0));
+
+        let mut picky = card.clone().with_preferences(
+            Preferences {
+                min_condition: Some(Condition::SlightlyPlayed),
+                zero_only: true,
+                ..Preferences::default()
+            },
+            1,
+        );
+        assert_eq!(amount(&picky), Some(3.0));
+
+        // Uma cópia a 3,00 e duas a 5,00
+        picky.quantity = 3;
+        assert!((amount(&picky).unwrap() - 13.0 / 3.0).abs() < 1e-9);
+
+        picky.preferences.languages = vec!["jp".to_string()];
+        assert_eq!(amount(&picky), None);
+    }
+
+    #[test]
+    fn quantities_without_enough_copies_have_no_price() {
+        let products = vec![
+            product(100, 1, "Near Mint", true),
+            product(200, 2, "Near Mint", true),
+        ];
+        let mut card = CardRef {
+            card_name: "Lightning Bolt".to_string(),
+            expansion_name: "Magic 2011".to_string(),
+            version: String::new(),
+            blueprint_id: Some(1),
+            quantity: 3,
+            preferences: Preferences::default(),
+        };
+        assert!(matches!(
+            select_listings(&products, &card),
+            PriceOutcome::Quote(_)
+        ));
+
+        card.quantity = 4;
+        assert!(matches!(
+            select_listings(&products, &card),
+            PriceOutcome::NoListings
+        ));
+        card.quantity = 0;
+        assert!(matches!(
+            select_listings(&products, &card),
+            PriceOutcome::NoListings
+        ));
+    }
+}
@@ -0,0 +1,209 @@
+mod fixture;
+mod headless;
+mod marketplace;
+mod webdriver;
+
+pub use fixture::FixturePriceSource;
+pub use headless::HeadlessChromePriceSource;
+pub use marketplace::MarketplacePriceSource;
+pub use webdriver::FantocciniPriceSource;
+
+use crate::api::blueprint::BlueprintData;
+use crate::api::CardTraderClient;
+use crate::error::AppError;
+use crate::preferences::Preferences;
+use crate::wishlist::WishlistItem;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::sync::Arc;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PriceQuote {
+    pub amount: f64,
+    pub currency: String,
+    #[serde(default)]
+    pub listings: Option<u32>,
+    #[serde(default)]
+    pub seller: Option<String>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub foil: Option<bool>,
+    // Vendedor envia pelo CardTrader Zero
+    #[serde(default)]
+    pub zero: Option<bool>,
+    #[serde(default = "Utc::now")]
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl PriceQuote {
+    pub fn new(amount: f64, currency: &str) -> Self {
+        PriceQuote {
+            amount,
+            currency: currency.to_string(),
+            listings: None,
+            seller: None,
+            condition: None,
+            language: None,
+            foil: None,
+            zero: None,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    pub fn display_amount(&self) -> String {
+        format_amount(self.amount, &self.currency)
+    }
+}
+
+// "R$ 12.50", "€ 3.20"; moedas desconhecidas usam o código ISO
+pub fn format_amount(amount: f64, currency: &str) -> String {
+    let symbol = match currency {
+        "BRL" => "R$",
+        "EUR" => "€",
+        "USD" => "$",
+        "GBP" => "£",
+        other => other,
+    };
+    format!("{} {:.2}", symbol, amount)
+}
+
+// Uma consulta que não falhou: ou encontramos um preço, ou a carta não tem
+// anúncios. Falhas de rede/scraping são retornadas como Err.
+#[derive(Clone)]
+pub enum PriceOutcome {
+    Quote(PriceQuote),
+    NoListings,
+}
+
+// Carta cujo preço será consultado, montada a partir de um item da wishlist
+// ou de um blueprint do cache.
+#[derive(Clone)]
+pub struct CardRef {
+    pub card_name: String,
+    pub expansion_name: String,
+    pub version: String,
+    pub blueprint_id: Option<u32>,
+    // Cópias desejadas: o preço é o valor por cópia das mais baratas
+    pub quantity: u32,
+    pub preferences: Preferences,
+}
+
+impl CardRef {
+    pub fn with_preferences(mut self, preferences: Preferences, quantity: u32) -> Self {
+        self.preferences = preferences;
+        self.quantity = quantity.max(1);
+        self
+    }
+
+    // Item sem preferências próprias usa as padrão
+    pub fn for_item(item: &WishlistItem, defaults: &Preferences) -> Self {
+        let preferences = item.preferences.clone().unwrap_or_else(|| defaults.clone());
+        CardRef::from(item).with_preferences(preferences, item.quantity)
+    }
+
+    pub fn key(&self) -> String {
+        match self.blueprint_id {
+            Some(blueprint_id) => blueprint_id.to_string(),
+            None => format!(
+                "{}|{}|{}",
+                self.card_name, self.expansion_name, self.version
+            ),
+        }
+    }
+}
+
+impl From<&WishlistItem> for CardRef {
+    fn from(item: &WishlistItem) -> Self {
+        CardRef {
+            card_name: item.card_name.clone(),
+            expansion_name: item.expansion_name.clone(),
+            version: item.version.clone(),
+            blueprint_id: item.blueprint_id,
+            quantity: item.quantity.max(1),
+            preferences: item.preferences.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&BlueprintData> for CardRef {
+    fn from(blueprint: &BlueprintData) -> Self {
+        CardRef {
+            card_name: blueprint.card_name.clone(),
+            expansion_name: blueprint.expansion_name.clone(),
+            version: blueprint.version.clone().unwrap_or_default(),
+            blueprint_id: Some(blueprint.blueprint_id),
+            quantity: 1,
+            preferences: Preferences::default(),
+        }
+    }
+}
+
+// A caixa de preço da página mostra só o anúncio mais barato, sem condição,
+// idioma, vendedor ou quantidade à venda. Com preferências ou várias cópias
+// o preço dela pode não ser o que o item pede, então a consulta falha em vez
+// de devolver esse preço.
+fn check_scrape_support(card: &CardRef) -> Result<(), AppError> {
+    if !card.preferences.is_any() {
+        return Err(AppError::Config(format!(
+            "{} has listing preferences ({}), which need PRICE_SOURCE=api",
+            card.card_name, card.preferences
+        )));
+    }
+    if card.quantity > 1 {
+        return Err(AppError::Config(format!(
+            "{} wants {} copies, which need PRICE_SOURCE=api",
+            card.card_name, card.quantity
+        )));
+    }
+    Ok(())
+}
+
+#[async_trait]
+pub trait PriceSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn fetch_quote(&self, card: &CardRef) -> Result<PriceOutcome, AppError>;
+}
+
+// PRICE_SOURCE=api (padrão) | chrome | webdriver | fixture
+pub fn source_from_env(api: &CardTraderClient) -> Result<Arc<dyn PriceSource>, AppError> {
+    let source = env::var("PRICE_SOURCE").unwrap_or_else(|_| "api".to_string());
+    match source.as_str() {
+        "api" => Ok(Arc::new(
+            MarketplacePriceSource::new(api.clone())
+                .with_fallback(Arc::new(HeadlessChromePriceSource::new())),
+        )),
+        "chrome" => Ok(Arc::new(HeadlessChromePriceSource::new())),
+        "webdriver" => Ok(Arc::new(FantocciniPriceSource::from_env())),
+        "fixture" => {
+            let path = env::var("PRICE_FIXTURE_FILE")
+                .unwrap_or_else(|_| "price_fixtures.json".to_string());
+            Ok(Arc::new(FixturePriceSource::from_file(&path)?))
+        }
+        other => Err(AppError::Config(format!(
+            "unknown PRICE_SOURCE {:?}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrapers_only_quote_single_copies_without_preferences() {
+        let card = CardRef::from(&BlueprintData::default());
+        assert!(check_scrape_support(&card).is_ok());
+        let copies = card.clone().with_preferences(Preferences::default(), 2);
+        assert!(matches!(
+            check_scrape_support(&copies),
+            Err(AppError::Config(_))
+        ));
+    }
+}
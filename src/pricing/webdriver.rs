@@ -0,0 +1,83 @@
+use super::headless::parse_price_text;
+use super::{CardRef, PriceOutcome, PriceQuote, PriceSource};
+use crate::error::AppError;
+use crate::slug;
+use async_trait::async_trait;
+use fantoccini::wd::Capabilities;
+use fantoccini::{Client, ClientBuilder, Locator};
+use std::env;
+
+const DEFAULT_WEBDRIVER_URL: &str = "http://localhost:9515";
+
+// Consulta a página da carta através de um servidor WebDriver (chromedriver).
+pub struct FantocciniPriceSource {
+    webdriver_url: String,
+}
+
+impl FantocciniPriceSource {
+    pub fn new(webdriver_url: &str) -> Self {
+        FantocciniPriceSource {
+            webdriver_url: webdriver_url.to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let webdriver_url =
+            env::var("WEBDRIVER_URL").unwrap_or_else(|_| DEFAULT_WEBDRIVER_URL.to_string());
+        FantocciniPriceSource::new(&webdriver_url)
+    }
+}
+
+#[async_trait]
+impl PriceSource for FantocciniPriceSource {
+    fn name(&self) -> &'static str {
+        "fantoccini"
+    }
+
+    async fn fetch_quote(&self, card: &CardRef) -> Result<PriceOutcome, AppError> {
+        super::check_scrape_support(card)?;
+        let cap: Capabilities = serde_json::from_str(
+            r#"{"browserName":"chrome","goog:chromeOptions":{"args":["--headless"]}}"#,
+        )
+        .unwrap();
+
+        let c = ClientBuilder::native()
+            .capabilities(cap)
+            .connect(&self.webdriver_url)
+            .await
+            .map_err(AppError::browser)?;
+
+        let url = slug::card_url(&card.card_name, &card.version, &card.expansion_name);
+        let outcome = fetch_page_price(&c, &url).await;
+
+        // Fecha o cliente
+        c.close().await.map_err(AppError::browser)?;
+
+        outcome
+    }
+}
+
+async fn fetch_page_price(c: &Client, url: &str) -> Result<PriceOutcome, AppError> {
+    // Navegar para a URL e obter o preço
+    c.goto(url).await.map_err(AppError::browser)?;
+
+    // A página carregou mas não tem caixa de preço: carta sem anúncios
+    if c.wait()
+        .for_element(Locator::Css("div.price-box__price"))
+        .await
+        .is_err()
+    {
+        return Ok(PriceOutcome::NoListings);
+    }
+
+    let price_text = c
+        .find(Locator::Css("div.price-box__price"))
+        .await
+        .map_err(AppError::browser)?
+        .text()
+        .await
+        .map_err(AppError::browser)?;
+
+    let amount = parse_price_text(&price_text)?;
+    Ok(PriceOutcome::Quote(PriceQuote::new(amount, "BRL")))
+}
@@ -0,0 +1,80 @@
+use crate::error::AppError;
+use crate::i18n::Locale;
+use crate::notify::{AlertRules, AlertTemplates, Channel};
+use crate::preferences::Preferences;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+
+// Preferências do usuário, em settings.json (ou CARDTRADER_SETTINGS)
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct Settings {
+    #[serde(default)]
+    pub default_game_id: Option<u32>,
+    // Preferências dos itens da wishlist que não têm as suas
+    #[serde(default)]
+    pub preferences: Preferences,
+    // Para onde vão os alertas das wishlists sem canais próprios
+    #[serde(default)]
+    pub alert_channels: Vec<Channel>,
+    #[serde(default)]
+    pub locale: Locale,
+    // Vazios usam os templates do idioma
+    #[serde(default)]
+    pub alert_templates: AlertTemplates,
+    // Repetição dos alertas e resumo diário
+    #[serde(default)]
+    pub alert_rules: AlertRules,
+}
+
+fn settings_path() -> PathBuf {
+    env::var("CARDTRADER_SETTINGS")
+        .unwrap_or_else(|_| "settings.json".to_string())
+        .into()
+}
+
+impl Settings {
+    pub fn load() -> Result<Settings, AppError> {
+        Settings::load_from(&settings_path())
+    }
+
+    fn load_from(path: &Path) -> Result<Settings, AppError> {
+        match File::open(path) {
+            Ok(file) => Ok(serde_json::from_reader(BufReader::new(file))?),
+            // Ainda não há preferências salvas. Qualquer outro erro sobe: o
+            // menu salvaria os padrões por cima das preferências verdadeiras
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Settings::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), AppError> {
+        fs::write(settings_path(), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_missing_settings_load_as_defaults() {
+        let dir = env::temp_dir().join(format!("cardtrader_settings_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let settings = Settings::load_from(&dir.join("settings.json")).unwrap();
+        assert_eq!(settings.default_game_id, None);
+
+        let not_a_dir = dir.join("not_a_dir");
+        fs::write(&not_a_dir, "").unwrap();
+        assert!(Settings::load_from(&not_a_dir.join("settings.json")).is_err());
+        // Um arquivo inválido também não vira as preferências padrão
+        assert!(Settings::load_from(&not_a_dir).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
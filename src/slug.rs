@@ -0,0 +1,230 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+pub const CARDS_BASE_URL: &str = "https://www.cardtrader.com/cards";
+
+// Letras que não se decompõem em letra base + acento
+fn transliterate(c: char) -> Option<&'static str> {
+    match c {
+        'Æ' | 'æ' => Some("ae"),
+        'Œ' | 'œ' => Some("oe"),
+        'Ø' | 'ø' => Some("o"),
+        'ß' => Some("ss"),
+        'Þ' | 'þ' => Some("th"),
+        'Đ' | 'đ' | 'Ð' | 'ð' => Some("d"),
+        'Ł' | 'ł' => Some("l"),
+        _ => None,
+    }
+}
+
+fn is_apostrophe(c: char) -> bool {
+    matches!(c, '\'' | '’' | '‘' | 'ʼ')
+}
+
+// Pontuação que some sem virar separador ("Jace, the Mind Sculptor",
+// "Borrowing 100,000 Arrows", "Dr. Who")
+fn is_dropped(c: char) -> bool {
+    matches!(c, '.' | ',' | ':' | '!' | '?' | '"' | '“' | '”')
+}
+
+// Remove acentos e translitera letras especiais ("Lim-Dûl" vira "Lim-Dul")
+pub fn fold(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            transliterate(c)
+                .map(str::to_string)
+                .unwrap_or(c.to_string())
+        })
+        .collect::<String>()
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect()
+}
+
+pub fn slugify(text: &str) -> String {
+    let ascii = fold(text);
+
+    let chars: Vec<char> = ascii.chars().collect();
+    let mut slug = String::with_capacity(chars.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if is_dropped(c) {
+            continue;
+        } else if is_apostrophe(c) {
+            // "Urza's" vira "urza-s", mas "Commanders' Arsenal" só perde o apóstrofo
+            if chars.get(i + 1).is_some_and(|next| next.is_alphanumeric()) {
+                slug.push('-');
+            }
+        } else if !slug.ends_with('-') {
+            // Espaços, "//" de split cards, hífens e o resto viram um único separador
+            slug.push('-');
+        }
+    }
+
+    slug.trim_matches('-').to_string()
+}
+
+pub fn card_slug(card_name: &str, version: &str, expansion_name: &str) -> String {
+    [card_name, version, expansion_name]
+        .iter()
+        .map(|part| slugify(part))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+pub fn card_url(card_name: &str, version: &str, expansion_name: &str) -> String {
+    format!(
+        "{}/{}",
+        CARDS_BASE_URL,
+        card_slug(card_name, version, expansion_name)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_handles_known_names() {
+        let cases = [
+            ("Lightning Bolt", "lightning-bolt"),
+            ("Black Lotus", "black-lotus"),
+            ("Urza's Saga", "urza-s-saga"),
+            ("Jace, the Mind Sculptor", "jace-the-mind-sculptor"),
+            ("Borrowing 100,000 Arrows", "borrowing-100000-arrows"),
+            ("Fire // Ice", "fire-ice"),
+            ("Wear // Tear", "wear-tear"),
+            ("Commit // Memory", "commit-memory"),
+            ("Æther Vial", "aether-vial"),
+            ("Ætherize", "aetherize"),
+            ("Lim-Dûl's Vault", "lim-dul-s-vault"),
+            ("Jötun Grunt", "jotun-grunt"),
+            ("Séance", "seance"),
+            ("Dandân", "dandan"),
+            ("Márton Stromgald", "marton-stromgald"),
+            ("Ach! Hans, Run!", "ach-hans-run"),
+            ("Kongming, \"Sleeping Dragon\"", "kongming-sleeping-dragon"),
+            ("Circle of Protection: Red", "circle-of-protection-red"),
+            ("Dr. Julius Jumblemorph", "dr-julius-jumblemorph"),
+            (
+                "Who // What // When // Where // Why",
+                "who-what-when-where-why",
+            ),
+            ("Sol Ring", "sol-ring"),
+            ("Pokémon Center", "pokemon-center"),
+            ("Pikachu ex", "pikachu-ex"),
+            ("Monkey.D.Luffy", "monkeydluffy"),
+            ("Blue-Eyes White Dragon", "blue-eyes-white-dragon"),
+            ("Tawnos's Coffin", "tawnos-s-coffin"),
+            ("Urza’s Tower", "urza-s-tower"),
+            ("  Trailing  Spaces  ", "trailing-spaces"),
+            (
+                "Elesh Norn // The Argent Etching",
+                "elesh-norn-the-argent-etching",
+            ),
+            ("Smørgåsbord", "smorgasbord"),
+            ("Straße", "strasse"),
+            ("Bartolomé del Presidio", "bartolome-del-presidio"),
+            ("Ifh-Bíff Efreet", "ifh-biff-efreet"),
+            ("R&D's Secret Lair", "r-d-s-secret-lair"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(slugify(input), expected, "slugify({:?})", input);
+        }
+    }
+
+    #[test]
+    fn slugify_handles_expansion_names() {
+        let cases = [
+            ("Magic 2011", "magic-2011"),
+            ("Modern Horizons 3", "modern-horizons-3"),
+            ("Commanders' Arsenal", "commanders-arsenal"),
+            ("Urza's Legacy", "urza-s-legacy"),
+            (
+                "Duel Decks: Elves vs. Goblins",
+                "duel-decks-elves-vs-goblins",
+            ),
+            ("Ravnica: City of Guilds", "ravnica-city-of-guilds"),
+            ("Time Spiral \"Timeshifted\"", "time-spiral-timeshifted"),
+            ("Scarlet & Violet", "scarlet-violet"),
+            (
+                "Sword & Shield—Brilliant Stars",
+                "sword-shield-brilliant-stars",
+            ),
+            ("Mystery Booster 2", "mystery-booster-2"),
+            (
+                "Commander Legends: Battle for Baldur's Gate",
+                "commander-legends-battle-for-baldur-s-gate",
+            ),
+            ("Tales of Middle-earth", "tales-of-middle-earth"),
+            ("Secret Lair Drop Series", "secret-lair-drop-series"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(slugify(input), expected, "slugify({:?})", input);
+        }
+    }
+
+    #[test]
+    fn card_url_matches_cardtrader_pages() {
+        let cases = [
+            (
+                ("Lightning Bolt", "", "Magic 2011"),
+                "https://www.cardtrader.com/cards/lightning-bolt-magic-2011",
+            ),
+            (
+                ("Urza's Saga", "", "Modern Horizons 2"),
+                "https://www.cardtrader.com/cards/urza-s-saga-modern-horizons-2",
+            ),
+            (
+                ("Fire // Ice", "", "Apocalypse"),
+                "https://www.cardtrader.com/cards/fire-ice-apocalypse",
+            ),
+            (
+                ("Æther Vial", "", "Darksteel"),
+                "https://www.cardtrader.com/cards/aether-vial-darksteel",
+            ),
+            (
+                ("Sol Ring", "Borderless", "Commander Masters"),
+                "https://www.cardtrader.com/cards/sol-ring-borderless-commander-masters",
+            ),
+            (
+                ("Ragavan, Nimble Pilferer", "Retro Frame", "Modern Horizons 2"),
+                "https://www.cardtrader.com/cards/ragavan-nimble-pilferer-retro-frame-modern-horizons-2",
+            ),
+            (
+                ("Tarmogoyf", "", "Commanders' Arsenal"),
+                "https://www.cardtrader.com/cards/tarmogoyf-commanders-arsenal",
+            ),
+            (
+                ("Lim-Dûl's Vault", "", "Alliances"),
+                "https://www.cardtrader.com/cards/lim-dul-s-vault-alliances",
+            ),
+            (
+                ("Charizard ex", "Special Illustration Rare", "Obsidian Flames"),
+                "https://www.cardtrader.com/cards/charizard-ex-special-illustration-rare-obsidian-flames",
+            ),
+            (
+                ("Séance", "", "Saviors of Kamigawa"),
+                "https://www.cardtrader.com/cards/seance-saviors-of-kamigawa",
+            ),
+        ];
+
+        for ((card_name, version, expansion_name), expected) in cases {
+            assert_eq!(card_url(card_name, version, expansion_name), expected);
+        }
+    }
+
+    #[test]
+    fn card_slug_skips_empty_parts() {
+        assert_eq!(card_slug("Island", "", "Unstable"), "island-unstable");
+        assert_eq!(card_slug("Island", "   ", "Unstable"), "island-unstable");
+        assert_eq!(
+            card_slug("Island", "Full Art", "Unstable"),
+            "island-full-art-unstable"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use super::Storage;
+use crate::api::blueprint::BlueprintData;
+use crate::api::expansion::Expansion;
+use crate::bot::BotUser;
+use crate::cache::sync::ExpansionSync;
+use crate::error::AppError;
+use crate::history::{HistoryStore, PriceObservation};
+use crate::notify::AlertState;
+use crate::pricing::PriceQuote;
+use crate::wishlist::{WishlistInfo, WishlistItem, DEFAULT_WISHLIST};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// Os arquivos JSON de sempre (all_blueprints.json, wishlist.json, ...). As
+// outras wishlists ficam em wishlist-<nome>.json e a lista delas, com a
+// configuração de cada uma, em wishlists.json. Os chats do bot ficam em
+// bot_users.json e os alertas já enviados em alert_state.json. Cada
+// arquivo é reescrito inteiro, por isso as escritas passam por um lock e por
+// um arquivo temporário renomeado no final.
+pub struct JsonStorage {
+    dir: PathBuf,
+    history: HistoryStore,
+    lock: Mutex<()>,
+}
+
+impl JsonStorage {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        let dir = dir.as_ref().to_path_buf();
+        JsonStorage {
+            history: HistoryStore::new(dir.join(crate::history::DEFAULT_HISTORY_FILE)),
+            dir,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read<T: DeserializeOwned>(&self, file_name: &str) -> Result<Vec<T>, AppError> {
+        match File::open(self.dir.join(file_name)) {
+            Ok(file) => Ok(serde_json::from_reader(BufReader::new(file))?),
+            // Arquivo ainda não existe; qualquer outro erro não pode virar
+            // uma lista vazia, que seria gravada por cima da verdadeira
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write<T: Serialize>(&self, file_name: &str, values: &[T]) -> Result<(), AppError> {
+        let path = self.dir.join(file_name);
+        let tmp_path = self.dir.join(format!("{}.tmp", file_name));
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        serde_json::to_writer_pretty(&mut writer, values)?;
+        writer.flush()?;
+        drop(writer);
+        fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    fn wishlist_file(list: &str) -> String {
+        if list == DEFAULT_WISHLIST {
+            "wishlist.json".to_string()
+        } else {
+            format!("wishlist-{}.json", list)
+        }
+    }
+
+    // Chamado com o lock já adquirido
+    fn upsert_blueprints(
+        &self,
+        blueprints: &[BlueprintData],
+        removed: &[u32],
+    ) -> Result<(), AppError> {
+        let mut all_blueprints = self.load_blueprints()?;
+        all_blueprints.retain(|blueprint| !removed.contains(&blueprint.blueprint_id));
+        let mut positions: BTreeMap<u32, usize> = all_blueprints
+            .iter()
+            .enumerate()
+            .map(|(position, blueprint)| (blueprint.blueprint_id, position))
+            .collect();
+        for blueprint in blueprints {
+            match positions.get(&blueprint.blueprint_id) {
+                Some(&position) => all_blueprints[position] = blueprint.clone(),
+                None => {
+                    positions.insert(blueprint.blueprint_id, all_blueprints.len());
+                    all_blueprints.push(blueprint.clone());
+                }
+            }
+        }
+        self.write("all_blueprints.json", &all_blueprints)
+    }
+}
+
+impl Default for JsonStorage {
+    fn default() -> Self {
+        JsonStorage::new(".")
+    }
+}
+
+impl Storage for JsonStorage {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn load_expansions(&self) -> Result<Vec<Expansion>, AppError> {
+        self.read("expansions.json")
+    }
+
+    fn save_expansions(&self, expansions: &[Expansion]) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut by_id: BTreeMap<u32, Expansion> = self
+            .load_expansions()?
+            .into_iter()
+            .map(|expansion| (expansion.id, expansion))
+            .collect();
+        for expansion in expansions {
+            by_id.insert(expansion.id, expansion.clone());
+        }
+        self.write("expansions.json", &by_id.into_values().collect::<Vec<_>>())
+    }
+
+    fn load_blueprints(&self) -> Result<Vec<BlueprintData>, AppError> {
+        self.read("all_blueprints.json")
+    }
+
+    fn save_blueprints(&self, blueprints: &[BlueprintData]) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        self.upsert_blueprints(blueprints, &[])
+    }
+
+    fn load_expansion_syncs(&self) -> Result<Vec<ExpansionSync>, AppError> {
+        self.read("expansion_syncs.json")
+    }
+
+    fn apply_blueprint_sync(
+        &self,
+        syncs: &[ExpansionSync],
+        upserts: &[BlueprintData],
+        removed: &[u32],
+    ) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        self.upsert_blueprints(upserts, removed)?;
+
+        let mut by_id: BTreeMap<u32, ExpansionSync> = self
+            .load_expansion_syncs()?
+            .into_iter()
+            .map(|sync| (sync.expansion_id, sync))
+            .collect();
+        for sync in syncs {
+            by_id.insert(sync.expansion_id, sync.clone());
+        }
+        self.write(
+            "expansion_syncs.json",
+            &by_id.into_values().collect::<Vec<_>>(),
+        )
+    }
+
+    fn wishlists(&self) -> Result<Vec<WishlistInfo>, AppError> {
+        Ok(super::with_default_wishlist(self.read("wishlists.json")?))
+    }
+
+    fn save_wishlist_info(&self, info: &WishlistInfo) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut lists: Vec<WishlistInfo> = self.read("wishlists.json")?;
+        match lists.iter_mut().find(|list| list.name == info.name) {
+            Some(list) => *list = info.clone(),
+            None => lists.push(info.clone()),
+        }
+        self.write("wishlists.json", &lists)
+    }
+
+    fn rename_wishlist(&self, from: &str, to: &str) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut lists: Vec<WishlistInfo> = self.read("wishlists.json")?;
+        for list in lists.iter_mut().filter(|list| list.name == from) {
+            list.name = to.to_string();
+        }
+        let path = self.dir.join(Self::wishlist_file(from));
+        if path.exists() {
+            fs::rename(path, self.dir.join(Self::wishlist_file(to)))?;
+        }
+        let mut states: Vec<AlertState> = self.read("alert_state.json")?;
+        for state in states.iter_mut().filter(|state| state.wishlist == from) {
+            state.rename(to);
+        }
+        self.write("alert_state.json", &states)?;
+        self.write("wishlists.json", &lists)
+    }
+
+    fn delete_wishlist(&self, name: &str) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut lists: Vec<WishlistInfo> = self.read("wishlists.json")?;
+        lists.retain(|list| list.name != name);
+        match fs::remove_file(self.dir.join(Self::wishlist_file(name))) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        let mut states: Vec<AlertState> = self.read("alert_state.json")?;
+        states.retain(|state| state.wishlist != name);
+        self.write("alert_state.json", &states)?;
+        self.write("wishlists.json", &lists)
+    }
+
+    fn load_wishlist(&self, list: &str) -> Result<Vec<WishlistItem>, AppError> {
+        self.read(&Self::wishlist_file(list))
+    }
+
+    fn add_wishlist_items(&self, list: &str, items: &[WishlistItem]) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut wishlist = self.load_wishlist(list)?;
+        wishlist.extend_from_slice(items);
+        self.write(&Self::wishlist_file(list), &wishlist)
+    }
+
+    fn save_wishlist(&self, list: &str, items: &[WishlistItem]) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        self.write(&Self::wishlist_file(list), items)
+    }
+
+    fn record_quotes(
+        &self,
+        list: &str,
+        quotes: &HashMap<String, PriceQuote>,
+    ) -> Result<Vec<WishlistItem>, AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut wishlist = self.load_wishlist(list)?;
+        for item in &mut wishlist {
+            if let Some(quote) = quotes.get(&item.key()) {
+                item.record_quote(quote);
+            }
+        }
+        self.write(&Self::wishlist_file(list), &wishlist)?;
+        Ok(wishlist)
+    }
+
+    fn bot_users(&self) -> Result<Vec<BotUser>, AppError> {
+        self.read("bot_users.json")
+    }
+
+    fn save_bot_user(&self, user: &BotUser) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut users: Vec<BotUser> = self.read("bot_users.json")?;
+        match users.iter_mut().find(|known| known.chat_id == user.chat_id) {
+            Some(known) => *known = user.clone(),
+            None => users.push(user.clone()),
+        }
+        self.write("bot_users.json", &users)
+    }
+
+    fn delete_bot_user(&self, chat_id: i64) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut users: Vec<BotUser> = self.read("bot_users.json")?;
+        users.retain(|user| user.chat_id != chat_id);
+        self.write("bot_users.json", &users)
+    }
+
+    fn alert_state(&self, list: &str) -> Result<AlertState, AppError> {
+        let states: Vec<AlertState> = self.read("alert_state.json")?;
+        Ok(states
+            .into_iter()
+            .find(|state| state.wishlist == list)
+            .unwrap_or_else(|| AlertState::new(list)))
+    }
+
+    fn save_alert_state(&self, state: &AlertState) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        let mut states: Vec<AlertState> = self.read("alert_state.json")?;
+        match states
+            .iter_mut()
+            .find(|known| known.wishlist == state.wishlist)
+        {
+            Some(known) => *known = state.clone(),
+            None => states.push(state.clone()),
+        }
+        self.write("alert_state.json", &states)
+    }
+
+    fn append_observations(&self, observations: &[PriceObservation]) -> Result<(), AppError> {
+        let _guard = self.lock.lock().unwrap();
+        self.history.append(observations)
+    }
+
+    fn observations(
+        &self,
+        card_key: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PriceObservation>, AppError> {
+        self.history.history_since(card_key, since)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_missing_files_read_as_empty() {
+        let dir = std::env::temp_dir().join(format!("cardtrader_json_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let storage = JsonStorage::new(&dir);
+        assert!(storage.load_wishlist(DEFAULT_WISHLIST).unwrap().is_empty());
+
+        // Um arquivo que existe mas não pode ser lido é um erro, não uma
+        // wishlist vazia
+        let not_a_dir = dir.join("not_a_dir");
+        fs::write(&not_a_dir, "").unwrap();
+        assert!(JsonStorage::new(&not_a_dir)
+            .load_wishlist(DEFAULT_WISHLIST)
+            .is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+pub mod json;
+pub mod sqlite;
+
+pub use json::JsonStorage;
+pub use sqlite::SqliteStorage;
+
+use crate::api::blueprint::BlueprintData;
+use crate::api::expansion::Expansion;
+use crate::bot::BotUser;
+use crate::cache::sync::ExpansionSync;
+use crate::error::AppError;
+use crate::history::PriceObservation;
+use crate::notify::AlertState;
+use crate::pricing::PriceQuote;
+use crate::wishlist::{WishlistInfo, WishlistItem, DEFAULT_WISHLIST};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+// Onde ficam expansões, blueprints, wishlist e histórico de preços. Cada
+// método de escrita é atômico: ou grava tudo, ou nada.
+pub trait Storage: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn load_expansions(&self) -> Result<Vec<Expansion>, AppError>;
+    // Insere ou atualiza pelo id
+    fn save_expansions(&self, expansions: &[Expansion]) -> Result<(), AppError>;
+
+    fn load_blueprints(&self) -> Result<Vec<BlueprintData>, AppError>;
+    // Insere ou atualiza pelo blueprint_id
+    fn save_blueprints(&self, blueprints: &[BlueprintData]) -> Result<(), AppError>;
+
+    fn load_expansion_syncs(&self) -> Result<Vec<ExpansionSync>, AppError>;
+    // Resultado de uma sincronização: grava os blueprints novos ou alterados,
+    // apaga os removidos e registra as expansões sincronizadas
+    fn apply_blueprint_sync(
+        &self,
+        syncs: &[ExpansionSync],
+        upserts: &[BlueprintData],
+        removed: &[u32],
+    ) -> Result<(), AppError>;
+
+    // Wishlists com nome; a "default" sempre existe e vem primeiro
+    fn wishlists(&self) -> Result<Vec<WishlistInfo>, AppError>;
+    // Cria ou atualiza a configuração de uma wishlist
+    fn save_wishlist_info(&self, info: &WishlistInfo) -> Result<(), AppError>;
+    // Renomeia levando os itens junto
+    fn rename_wishlist(&self, from: &str, to: &str) -> Result<(), AppError>;
+    // Apaga a wishlist e os seus itens
+    fn delete_wishlist(&self, name: &str) -> Result<(), AppError>;
+
+    fn load_wishlist(&self, list: &str) -> Result<Vec<WishlistItem>, AppError>;
+    fn add_wishlist_items(&self, list: &str, items: &[WishlistItem]) -> Result<(), AppError>;
+    // Substitui a wishlist inteira
+    fn save_wishlist(&self, list: &str, items: &[WishlistItem]) -> Result<(), AppError>;
+    // Grava as cotações (pela chave da carta) só nos itens que ainda estão na
+    // wishlist, sem mexer no resto: itens adicionados, removidos ou editados
+    // durante a consulta são mantidos. Devolve a wishlist atualizada.
+    fn record_quotes(
+        &self,
+        list: &str,
+        quotes: &HashMap<String, PriceQuote>,
+    ) -> Result<Vec<WishlistItem>, AppError>;
+
+    // Chats autorizados a usar o bot do Telegram
+    fn bot_users(&self) -> Result<Vec<BotUser>, AppError>;
+    // Insere ou atualiza pelo chat_id
+    fn save_bot_user(&self, user: &BotUser) -> Result<(), AppError>;
+    fn delete_bot_user(&self, chat_id: i64) -> Result<(), AppError>;
+
+    // Alertas já enviados de uma wishlist; vazio se ainda não há nenhum
+    fn alert_state(&self, list: &str) -> Result<AlertState, AppError>;
+    // Insere ou atualiza pela wishlist
+    fn save_alert_state(&self, state: &AlertState) -> Result<(), AppError>;
+
+    fn append_observations(&self, observations: &[PriceObservation]) -> Result<(), AppError>;
+    // Observações de uma carta em ordem cronológica
+    fn observations(
+        &self,
+        card_key: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PriceObservation>, AppError>;
+}
+
+// CARDTRADER_STORAGE=json (padrão) | sqlite; o banco fica em CARDTRADER_DB
+pub fn from_env() -> Result<Arc<dyn Storage>, AppError> {
+    let backend = env::var("CARDTRADER_STORAGE").unwrap_or_else(|_| "json".to_string());
+    match backend.as_str() {
+        "json" => Ok(Arc::new(JsonStorage::default())),
+        "sqlite" => {
+            let path = env::var("CARDTRADER_DB").unwrap_or_else(|_| "cardtrader.db".to_string());
+            Ok(Arc::new(SqliteStorage::open(&path)?))
+        }
+        other => Err(AppError::Config(format!(
+            "unknown CARDTRADER_STORAGE {:?}",
+            other
+        ))),
+    }
+}
+
+// Coloca a wishlist padrão no início, criando-a se ainda não foi configurada
+fn with_default_wishlist(mut lists: Vec<WishlistInfo>) -> Vec<WishlistInfo> {
+    match lists.iter().position(|list| list.name == DEFAULT_WISHLIST) {
+        Some(position) => {
+            let default = lists.remove(position);
+            lists.insert(0, default);
+        }
+        None => lists.insert(0, WishlistInfo::new(DEFAULT_WISHLIST)),
+    }
+    lists
+}
+
+// Copia tudo de um backend para outro, por exemplo dos arquivos JSON para o
+// SQLite. Retorna quantos itens de wishlist foram copiados.
+pub fn copy_all(from: &dyn Storage, to: &dyn Storage) -> Result<usize, AppError> {
+    to.save_expansions(&from.load_expansions()?)?;
+    to.apply_blueprint_sync(&from.load_expansion_syncs()?, &from.load_blueprints()?, &[])?;
+
+    let mut copied = 0;
+    let mut keys = Vec::new();
+    for list in from.wishlists()? {
+        let wishlist = from.load_wishlist(&list.name)?;
+        to.save_wishlist_info(&list)?;
+        to.save_wishlist(&list.name, &wishlist)?;
+        to.save_alert_state(&from.alert_state(&list.name)?)?;
+        copied += wishlist.len();
+        keys.extend(wishlist.iter().map(WishlistItem::key));
+    }
+    for user in from.bot_users()? {
+        to.save_bot_user(&user)?;
+    }
+
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        to.append_observations(&from.observations(&key, None)?)?;
+    }
+    Ok(copied)
+}
@@ -0,0 +1,706 @@
+use super::Storage;
+use crate::api::blueprint::BlueprintData;
+use crate::api::expansion::Expansion;
+use crate::bot::BotUser;
+use crate::cache::sync::ExpansionSync;
+use crate::error::AppError;
+use crate::history::PriceObservation;
+use crate::notify::AlertState;
+use crate::pricing::PriceQuote;
+use crate::wishlist::{WishlistInfo, WishlistItem};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+// Cada migração roda uma única vez, em ordem; a versão aplicada fica em
+// PRAGMA user_version. Nunca edite uma migração já publicada, crie outra.
+const MIGRATIONS: &[&str] = &[
+    // 1: esquema inicial. Blueprints e itens da wishlist guardam o registro
+    // completo em `data` (JSON) e repetem em colunas só o que é consultado.
+    "CREATE TABLE expansions (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL
+    );
+    CREATE TABLE blueprints (
+        blueprint_id INTEGER PRIMARY KEY,
+        card_name TEXT NOT NULL,
+        expansion_name TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX blueprints_card_name ON blueprints (card_name);
+    CREATE TABLE wishlist_items (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        card_name TEXT NOT NULL,
+        blueprint_id INTEGER,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE price_observations (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        card_key TEXT NOT NULL,
+        observed_at TEXT NOT NULL,
+        price REAL NOT NULL,
+        currency TEXT NOT NULL,
+        listings INTEGER,
+        source TEXT NOT NULL
+    );
+    CREATE INDEX price_observations_card ON price_observations (card_key, observed_at);",
+    // 2: sincronização incremental por expansão
+    "ALTER TABLE blueprints ADD COLUMN expansion_id INTEGER;
+    UPDATE blueprints SET expansion_id = json_extract(data, '$.expansion_id');
+    CREATE INDEX blueprints_expansion ON blueprints (expansion_id);
+    CREATE TABLE expansion_syncs (
+        expansion_id INTEGER PRIMARY KEY,
+        synced_at TEXT NOT NULL,
+        blueprint_count INTEGER NOT NULL
+    );",
+    // 3: jogo e categoria do blueprint, para filtrar por jogo e por singles
+    "ALTER TABLE blueprints ADD COLUMN game_id INTEGER;
+    ALTER TABLE blueprints ADD COLUMN category_id INTEGER;
+    UPDATE blueprints SET
+        game_id = json_extract(data, '$.game_id'),
+        category_id = json_extract(data, '$.category_id');
+    CREATE INDEX blueprints_game ON blueprints (game_id, category_id);",
+    // 4: jogo de cada expansão
+    "ALTER TABLE expansions ADD COLUMN game_id INTEGER;",
+    // 5: código da expansão ("MH3")
+    "ALTER TABLE expansions ADD COLUMN code TEXT;",
+    // 6: wishlists com nome; os itens existentes ficam na "default"
+    "CREATE TABLE wishlists (
+        name TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    );
+    ALTER TABLE wishlist_items ADD COLUMN wishlist TEXT NOT NULL DEFAULT 'default';
+    CREATE INDEX wishlist_items_wishlist ON wishlist_items (wishlist, id);",
+    // 7: chats autorizados a usar o bot
+    "CREATE TABLE bot_users (
+        chat_id INTEGER PRIMARY KEY,
+        data TEXT NOT NULL
+    );",
+    // 8: alertas já enviados de cada wishlist
+    "CREATE TABLE alert_state (
+        wishlist TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    );",
+];
+
+pub struct SqliteStorage {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteStorage {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, AppError> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, AppError> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(mut conn: Connection) -> Result<Self, AppError> {
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.busy_timeout(std::time::Duration::from_secs(5))?;
+        migrate(&mut conn)?;
+        Ok(SqliteStorage {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    pub fn schema_version(&self) -> Result<usize, AppError> {
+        let conn = self.conn.lock().unwrap();
+        Ok(conn.pragma_query_value(None, "user_version", |row| row.get(0))?)
+    }
+}
+
+fn migrate(conn: &mut Connection) -> Result<(), AppError> {
+    let current: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if current > MIGRATIONS.len() {
+        return Err(AppError::Config(format!(
+            "database schema version {} is newer than this program supports ({})",
+            current,
+            MIGRATIONS.len()
+        )));
+    }
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(current) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration)?;
+        tx.pragma_update(None, "user_version", index + 1)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+fn insert_wishlist_items(
+    tx: &rusqlite::Transaction,
+    list: &str,
+    items: &[WishlistItem],
+) -> Result<(), AppError> {
+    let mut statement = tx.prepare(
+        "INSERT INTO wishlist_items (wishlist, card_name, blueprint_id, data)
+         VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for item in items {
+        statement.execute(params![
+            list,
+            item.card_name,
+            item.blueprint_id,
+            serde_json::to_string(item)?
+        ])?;
+    }
+    Ok(())
+}
+
+fn upsert_alert_state(conn: &Connection, state: &AlertState) -> Result<(), AppError> {
+    conn.execute(
+        "INSERT INTO alert_state (wishlist, data) VALUES (?1, ?2)
+         ON CONFLICT (wishlist) DO UPDATE SET data = excluded.data",
+        params![state.wishlist, serde_json::to_string(state)?],
+    )?;
+    Ok(())
+}
+
+fn upsert_blueprints(
+    tx: &rusqlite::Transaction,
+    blueprints: &[BlueprintData],
+) -> Result<(), AppError> {
+    let mut statement = tx.prepare(
+        "INSERT INTO blueprints
+            (blueprint_id, card_name, expansion_name, expansion_id, game_id, category_id, data)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT (blueprint_id) DO UPDATE SET
+            card_name = excluded.card_name,
+            expansion_name = excluded.expansion_name,
+            expansion_id = excluded.expansion_id,
+            game_id = excluded.game_id,
+            category_id = excluded.category_id,
+            data = excluded.data",
+    )?;
+    for blueprint in blueprints {
+        statement.execute(params![
+            blueprint.blueprint_id,
+            blueprint.card_name,
+            blueprint.expansion_name,
+            blueprint.expansion_id,
+            blueprint.game_id,
+            blueprint.category_id,
+            serde_json::to_string(blueprint)?
+        ])?;
+    }
+    Ok(())
+}
+
+impl Storage for SqliteStorage {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn load_expansions(&self) -> Result<Vec<Expansion>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement =
+            conn.prepare("SELECT id, name, game_id, code FROM expansions ORDER BY id")?;
+        let expansions = statement
+            .query_map([], |row| {
+                Ok(Expansion {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    game_id: row.get(2)?,
+                    code: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(expansions)
+    }
+
+    fn save_expansions(&self, expansions: &[Expansion]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        {
+            let mut statement = tx.prepare(
+                "INSERT INTO expansions (id, name, game_id, code) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (id) DO UPDATE SET
+                    name = excluded.name,
+                    game_id = excluded.game_id,
+                    code = excluded.code",
+            )?;
+            for expansion in expansions {
+                statement.execute(params![
+                    expansion.id,
+                    expansion.name,
+                    expansion.game_id,
+                    expansion.code
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn load_blueprints(&self) -> Result<Vec<BlueprintData>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare("SELECT data FROM blueprints ORDER BY blueprint_id")?;
+        let rows = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.iter()
+            .map(|data| Ok(serde_json::from_str(data)?))
+            .collect()
+    }
+
+    fn save_blueprints(&self, blueprints: &[BlueprintData]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        upsert_blueprints(&tx, blueprints)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn load_expansion_syncs(&self) -> Result<Vec<ExpansionSync>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "SELECT expansion_id, synced_at, blueprint_count FROM expansion_syncs
+             ORDER BY expansion_id",
+        )?;
+        let syncs = statement
+            .query_map([], |row| {
+                Ok(ExpansionSync {
+                    expansion_id: row.get(0)?,
+                    synced_at: row.get(1)?,
+                    blueprint_count: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(syncs)
+    }
+
+    fn apply_blueprint_sync(
+        &self,
+        syncs: &[ExpansionSync],
+        upserts: &[BlueprintData],
+        removed: &[u32],
+    ) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        upsert_blueprints(&tx, upserts)?;
+        {
+            let mut delete = tx.prepare("DELETE FROM blueprints WHERE blueprint_id = ?1")?;
+            for blueprint_id in removed {
+                delete.execute([blueprint_id])?;
+            }
+            let mut record = tx.prepare(
+                "INSERT INTO expansion_syncs (expansion_id, synced_at, blueprint_count)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT (expansion_id) DO UPDATE SET
+                    synced_at = excluded.synced_at,
+                    blueprint_count = excluded.blueprint_count",
+            )?;
+            for sync in syncs {
+                record.execute(params![
+                    sync.expansion_id,
+                    sync.synced_at,
+                    sync.blueprint_count
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn wishlists(&self) -> Result<Vec<WishlistInfo>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare("SELECT data FROM wishlists ORDER BY name")?;
+        let rows = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        let lists = rows
+            .iter()
+            .map(|data| Ok(serde_json::from_str(data)?))
+            .collect::<Result<Vec<_>, AppError>>()?;
+        Ok(super::with_default_wishlist(lists))
+    }
+
+    fn save_wishlist_info(&self, info: &WishlistInfo) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO wishlists (name, data) VALUES (?1, ?2)
+             ON CONFLICT (name) DO UPDATE SET data = excluded.data",
+            params![info.name, serde_json::to_string(info)?],
+        )?;
+        Ok(())
+    }
+
+    fn rename_wishlist(&self, from: &str, to: &str) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "UPDATE wishlists
+             SET name = ?2, data = json_set(data, '$.name', ?2)
+             WHERE name = ?1",
+            params![from, to],
+        )?;
+        tx.execute(
+            "UPDATE wishlist_items SET wishlist = ?2 WHERE wishlist = ?1",
+            params![from, to],
+        )?;
+        let state: Option<String> = tx
+            .query_row(
+                "SELECT data FROM alert_state WHERE wishlist = ?1",
+                [from],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(data) = state {
+            let mut state: AlertState = serde_json::from_str(&data)?;
+            state.rename(to);
+            tx.execute("DELETE FROM alert_state WHERE wishlist = ?1", [from])?;
+            upsert_alert_state(&tx, &state)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn delete_wishlist(&self, name: &str) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM wishlists WHERE name = ?1", [name])?;
+        tx.execute("DELETE FROM wishlist_items WHERE wishlist = ?1", [name])?;
+        tx.execute("DELETE FROM alert_state WHERE wishlist = ?1", [name])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn load_wishlist(&self, list: &str) -> Result<Vec<WishlistItem>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement =
+            conn.prepare("SELECT data FROM wishlist_items WHERE wishlist = ?1 ORDER BY id")?;
+        let rows = statement
+            .query_map([list], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.iter()
+            .map(|data| Ok(serde_json::from_str(data)?))
+            .collect()
+    }
+
+    fn add_wishlist_items(&self, list: &str, items: &[WishlistItem]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        insert_wishlist_items(&tx, list, items)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn save_wishlist(&self, list: &str, items: &[WishlistItem]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM wishlist_items WHERE wishlist = ?1", [list])?;
+        insert_wishlist_items(&tx, list, items)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn record_quotes(
+        &self,
+        list: &str,
+        quotes: &HashMap<String, PriceQuote>,
+    ) -> Result<Vec<WishlistItem>, AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let mut items = Vec::new();
+        {
+            let mut statement =
+                tx.prepare("SELECT id, data FROM wishlist_items WHERE wishlist = ?1 ORDER BY id")?;
+            let rows = statement
+                .query_map([list], |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut update = tx.prepare("UPDATE wishlist_items SET data = ?2 WHERE id = ?1")?;
+            for (id, data) in rows {
+                let mut item: WishlistItem = serde_json::from_str(&data)?;
+                if let Some(quote) = quotes.get(&item.key()) {
+                    item.record_quote(quote);
+                    update.execute(params![id, serde_json::to_string(&item)?])?;
+                }
+                items.push(item);
+            }
+        }
+        tx.commit()?;
+        Ok(items)
+    }
+
+    fn bot_users(&self) -> Result<Vec<BotUser>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare("SELECT data FROM bot_users ORDER BY chat_id")?;
+        let rows = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.iter()
+            .map(|data| Ok(serde_json::from_str(data)?))
+            .collect()
+    }
+
+    fn save_bot_user(&self, user: &BotUser) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO bot_users (chat_id, data) VALUES (?1, ?2)
+             ON CONFLICT (chat_id) DO UPDATE SET data = excluded.data",
+            params![user.chat_id, serde_json::to_string(user)?],
+        )?;
+        Ok(())
+    }
+
+    fn delete_bot_user(&self, chat_id: i64) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM bot_users WHERE chat_id = ?1", [chat_id])?;
+        Ok(())
+    }
+
+    fn alert_state(&self, list: &str) -> Result<AlertState, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let data: Option<String> = conn
+            .query_row(
+                "SELECT data FROM alert_state WHERE wishlist = ?1",
+                [list],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match data {
+            Some(data) => Ok(serde_json::from_str(&data)?),
+            None => Ok(AlertState::new(list)),
+        }
+    }
+
+    fn save_alert_state(&self, state: &AlertState) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+        upsert_alert_state(&conn, state)
+    }
+
+    fn append_observations(&self, observations: &[PriceObservation]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        {
+            let mut statement = tx.prepare(
+                "INSERT INTO price_observations
+                    (card_key, observed_at, price, currency, listings, source)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for observation in observations {
+                statement.execute(params![
+                    observation.card_key,
+                    observation.timestamp,
+                    observation.price,
+                    observation.currency,
+                    observation.listings,
+                    observation.source
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn observations(
+        &self,
+        card_key: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PriceObservation>, AppError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "SELECT card_key, observed_at, price, currency, listings, source
+             FROM price_observations
+             WHERE card_key = ?1 AND (?2 IS NULL OR observed_at >= ?2)
+             ORDER BY observed_at",
+        )?;
+        let observations = statement
+            .query_map(params![card_key, since], |row| {
+                Ok(PriceObservation {
+                    card_key: row.get(0)?,
+                    timestamp: row.get(1)?,
+                    price: row.get(2)?,
+                    currency: row.get(3)?,
+                    listings: row.get(4)?,
+                    source: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(observations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pricing::PriceQuote;
+    use crate::wishlist::DEFAULT_WISHLIST;
+    use chrono::TimeDelta;
+
+    fn blueprint(blueprint_id: u32, card_name: &str) -> BlueprintData {
+        BlueprintData {
+            blueprint_id,
+            card_name: card_name.to_string(),
+            version: None,
+            collector_number: "1".to_string(),
+            expansion_name: "Alpha".to_string(),
+            expansion_id: Some(1),
+            ..BlueprintData::default()
+        }
+    }
+
+    #[test]
+    fn migrations_bring_a_new_database_to_the_latest_version() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        assert_eq!(storage.schema_version().unwrap(), MIGRATIONS.len());
+    }
+
+    #[test]
+    fn wishlist_and_blueprints_round_trip() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        storage
+            .save_blueprints(&[blueprint(1, "Sol Ring"), blueprint(2, "Mox Pearl")])
+            .unwrap();
+        storage
+            .save_blueprints(&[blueprint(1, "Sol Ring (renamed)")])
+            .unwrap();
+
+        let blueprints = storage.load_blueprints().unwrap();
+        assert_eq!(blueprints.len(), 2);
+        assert_eq!(blueprints[0].card_name, "Sol Ring (renamed)");
+
+        let mut item = WishlistItem::from_blueprint(&blueprints[1], 100.0);
+        item.record_quote(&PriceQuote::new(120.0, "EUR"));
+        storage
+            .add_wishlist_items(DEFAULT_WISHLIST, &[item.clone(), item])
+            .unwrap();
+        let wishlist = storage.load_wishlist(DEFAULT_WISHLIST).unwrap();
+        assert_eq!(wishlist.len(), 2);
+        assert_eq!(wishlist[0].last_price, Some(120.0));
+
+        storage
+            .save_wishlist(DEFAULT_WISHLIST, &wishlist[..1])
+            .unwrap();
+        assert_eq!(storage.load_wishlist(DEFAULT_WISHLIST).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn named_wishlists_keep_their_items_apart() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        let item = WishlistItem::from_blueprint(&blueprint(1, "Sol Ring"), 1.0);
+        storage
+            .add_wishlist_items(DEFAULT_WISHLIST, std::slice::from_ref(&item))
+            .unwrap();
+        let mut burn = WishlistInfo::new("burn");
+        burn.alerts.telegram_chat_id = Some(42);
+        storage.save_wishlist_info(&burn).unwrap();
+        storage
+            .add_wishlist_items("burn", &[item.clone(), item])
+            .unwrap();
+
+        storage.rename_wishlist("burn", "modern-burn").unwrap();
+        let names: Vec<String> = storage
+            .wishlists()
+            .unwrap()
+            .into_iter()
+            .map(|list| list.name)
+            .collect();
+        assert_eq!(names, vec!["default", "modern-burn"]);
+        assert_eq!(
+            storage.wishlists().unwrap()[1].alerts.telegram_chat_id,
+            Some(42)
+        );
+        assert_eq!(storage.load_wishlist("modern-burn").unwrap().len(), 2);
+
+        storage.delete_wishlist("modern-burn").unwrap();
+        assert_eq!(storage.wishlists().unwrap().len(), 1);
+        assert!(storage.load_wishlist("modern-burn").unwrap().is_empty());
+        assert_eq!(storage.load_wishlist(DEFAULT_WISHLIST).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn alert_state_follows_its_wishlist() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        storage
+            .save_wishlist_info(&WishlistInfo::new("burn"))
+            .unwrap();
+        assert_eq!(
+            storage.alert_state("burn").unwrap(),
+            AlertState::new("burn")
+        );
+
+        let mut state = AlertState::new("burn");
+        state.last_digest_at = Some(Utc::now());
+        storage.save_alert_state(&state).unwrap();
+        storage.save_alert_state(&state).unwrap();
+
+        storage.rename_wishlist("burn", "modern-burn").unwrap();
+        assert_eq!(storage.alert_state("burn").unwrap().last_digest_at, None);
+        let renamed = storage.alert_state("modern-burn").unwrap();
+        assert_eq!(renamed.wishlist, "modern-burn");
+        assert_eq!(renamed.last_digest_at, state.last_digest_at);
+
+        storage.delete_wishlist("modern-burn").unwrap();
+        assert_eq!(
+            storage.alert_state("modern-burn").unwrap().last_digest_at,
+            None
+        );
+    }
+
+    #[test]
+    fn blueprint_sync_removes_records_and_remembers_expansions() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        storage
+            .save_blueprints(&[blueprint(1, "Sol Ring"), blueprint(2, "Mox Pearl")])
+            .unwrap();
+
+        let sync = ExpansionSync {
+            expansion_id: 1,
+            synced_at: Utc::now(),
+            blueprint_count: 2,
+        };
+        storage
+            .apply_blueprint_sync(&[sync], &[blueprint(3, "Time Walk")], &[2])
+            .unwrap();
+
+        let ids: Vec<u32> = storage
+            .load_blueprints()
+            .unwrap()
+            .iter()
+            .map(|blueprint| blueprint.blueprint_id)
+            .collect();
+        assert_eq!(ids, vec![1, 3]);
+        assert_eq!(
+            storage.load_expansion_syncs().unwrap()[0].blueprint_count,
+            2
+        );
+    }
+
+    #[test]
+    fn observations_are_filtered_by_card_and_time() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        let observation = |card_key: &str, days_ago: i64, price: f64| PriceObservation {
+            timestamp: Utc::now() - TimeDelta::days(days_ago),
+            card_key: card_key.to_string(),
+            price,
+            currency: "EUR".to_string(),
+            listings: Some(2),
+            source: "fixture".to_string(),
+        };
+        storage
+            .append_observations(&[
+                observation("1", 1, 3.0),
+                observation("1", 10, 5.0),
+                observation("2", 1, 9.0),
+            ])
+            .unwrap();
+
+        let all = storage.observations("1", None).unwrap();
+        assert_eq!(
+            all.iter().map(|o| o.price).collect::<Vec<_>>(),
+            vec![5.0, 3.0]
+        );
+        let recent = storage
+            .observations("1", Some(Utc::now() - TimeDelta::days(7)))
+            .unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].listings, Some(2));
+    }
+}
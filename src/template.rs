@@ -0,0 +1,247 @@
+// Templates no estilo Handlebars para as mensagens:
+//
+//   {{card}}                      variável
+//   {{#if link}}...{{else}}...{{/if}}   só quando a variável não está vazia
+//   {{#bold}}...{{/bold}}         negrito, no formato de cada canal
+//
+// O texto fixo e as variáveis passam pelo escape do canal, então o mesmo
+// template serve para Telegram, Discord, Slack e texto puro.
+
+use std::collections::BTreeSet;
+
+// Como cada canal escreve o texto
+pub trait Markup {
+    fn escape(&self, text: &str) -> String;
+
+    fn bold(&self, text: &str) -> String {
+        text.to_string()
+    }
+}
+
+pub struct Plain;
+
+impl Markup for Plain {
+    fn escape(&self, text: &str) -> String {
+        text.to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Var(String),
+    If(String, Vec<Node>, Vec<Node>),
+    Bold(Vec<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+// Bloco ainda aberto durante a leitura
+enum Block {
+    If {
+        var: String,
+        then: Vec<Node>,
+        in_else: bool,
+    },
+    Bold,
+}
+
+fn is_variable(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Template, String> {
+        // Cada bloco aberto guarda os nós de fora dele
+        let mut stack: Vec<(Block, Vec<Node>)> = Vec::new();
+        let mut nodes = Vec::new();
+        let mut rest = source;
+
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                nodes.push(Node::Text(rest[..start].to_string()));
+            }
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| format!("unclosed {{{{ in {:?}", &rest[start..]))?;
+            let tag = after[..end].trim();
+            rest = &after[end + 2..];
+
+            if let Some(var) = tag.strip_prefix("#if ") {
+                let var = var.trim();
+                if !is_variable(var) {
+                    return Err(format!("invalid variable {:?} in {{{{{}}}}}", var, tag));
+                }
+                let block = Block::If {
+                    var: var.to_string(),
+                    then: Vec::new(),
+                    in_else: false,
+                };
+                stack.push((block, std::mem::take(&mut nodes)));
+            } else if tag == "#bold" {
+                stack.push((Block::Bold, std::mem::take(&mut nodes)));
+            } else if tag == "else" {
+                match stack.last_mut() {
+                    Some((Block::If { then, in_else, .. }, _)) if !*in_else => {
+                        *then = std::mem::take(&mut nodes);
+                        *in_else = true;
+                    }
+                    _ => return Err("{{else}} outside of {{#if}}".to_string()),
+                }
+            } else if tag == "/if" || tag == "/bold" {
+                let node = match stack.pop() {
+                    Some((Block::If { var, then, in_else }, outer)) if tag == "/if" => {
+                        let inner = std::mem::replace(&mut nodes, outer);
+                        if in_else {
+                            Node::If(var, then, inner)
+                        } else {
+                            Node::If(var, inner, Vec::new())
+                        }
+                    }
+                    Some((Block::Bold, outer)) if tag == "/bold" => {
+                        Node::Bold(std::mem::replace(&mut nodes, outer))
+                    }
+                    _ => return Err(format!("unexpected {{{{{}}}}}", tag)),
+                };
+                nodes.push(node);
+            } else if is_variable(tag) {
+                nodes.push(Node::Var(tag.to_string()));
+            } else {
+                return Err(format!("unknown tag {{{{{}}}}}", tag));
+            }
+        }
+        if !rest.is_empty() {
+            nodes.push(Node::Text(rest.to_string()));
+        }
+
+        match stack.last() {
+            Some((Block::If { .. }, _)) => Err("{{#if}} without {{/if}}".to_string()),
+            Some((Block::Bold, _)) => Err("{{#bold}} without {{/bold}}".to_string()),
+            None => Ok(Template { nodes }),
+        }
+    }
+
+    // Nomes das variáveis usadas, em ordem e sem repetição
+    pub fn variables(&self) -> BTreeSet<&str> {
+        fn collect<'a>(nodes: &'a [Node], names: &mut BTreeSet<&'a str>) {
+            for node in nodes {
+                match node {
+                    Node::Text(_) => {}
+                    Node::Var(var) => {
+                        names.insert(var);
+                    }
+                    Node::If(var, then, otherwise) => {
+                        names.insert(var);
+                        collect(then, names);
+                        collect(otherwise, names);
+                    }
+                    Node::Bold(inner) => collect(inner, names),
+                }
+            }
+        }
+        let mut names = BTreeSet::new();
+        collect(&self.nodes, &mut names);
+        names
+    }
+
+    // Recusa variáveis que o template não vai receber, para o erro aparecer
+    // ao salvar e não no meio de um alerta
+    pub fn check_variables(&self, known: &[&str]) -> Result<(), String> {
+        match self
+            .variables()
+            .into_iter()
+            .find(|var| !known.contains(var))
+        {
+            Some(var) => Err(format!(
+                "unknown variable {:?}, use one of: {}",
+                var,
+                known.join(", ")
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // Variáveis que faltam viram texto vazio
+    pub fn render(&self, vars: &[(&str, String)], markup: &dyn Markup) -> String {
+        fn value<'a>(vars: &'a [(&str, String)], name: &str) -> &'a str {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.as_str())
+                .unwrap_or("")
+        }
+        fn render_nodes(nodes: &[Node], vars: &[(&str, String)], markup: &dyn Markup) -> String {
+            nodes
+                .iter()
+                .map(|node| match node {
+                    Node::Text(text) => markup.escape(text),
+                    Node::Var(var) => markup.escape(value(vars, var)),
+                    Node::If(var, then, otherwise) => {
+                        let branch = if value(vars, var).is_empty() {
+                            otherwise
+                        } else {
+                            then
+                        };
+                        render_nodes(branch, vars, markup)
+                    }
+                    Node::Bold(inner) => markup.bold(&render_nodes(inner, vars, markup)),
+                })
+                .collect()
+        }
+        render_nodes(&self.nodes, vars, markup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Stars;
+
+    impl Markup for Stars {
+        fn escape(&self, text: &str) -> String {
+            text.replace('*', "\\*")
+        }
+
+        fn bold(&self, text: &str) -> String {
+            format!("*{}*", text)
+        }
+    }
+
+    #[test]
+    fn templates_render_variables_conditions_and_bold() {
+        let template =
+            Template::parse("{{#bold}}{{card}}{{/bold}} {{#if link}}<{{link}}>{{else}}*{{/if}}")
+                .unwrap();
+        let vars = [("card", "Sol *Ring*".to_string()), ("link", String::new())];
+        assert_eq!(template.render(&vars, &Stars), "*Sol \\*Ring\\** \\*");
+        assert_eq!(template.render(&vars, &Plain), "Sol *Ring* *");
+
+        let vars = [("card", "Mox".to_string()), ("link", "x".to_string())];
+        assert_eq!(template.render(&vars, &Plain), "Mox <x>");
+
+        assert!(template.check_variables(&["card", "link"]).is_ok());
+        assert!(template.check_variables(&["card"]).is_err());
+    }
+
+    #[test]
+    fn broken_templates_are_rejected() {
+        for source in [
+            "{{card",
+            "{{#if card}}x",
+            "{{/bold}}",
+            "{{#bold}}{{/if}}",
+            "{{else}}",
+            "{{Card Name}}",
+            "{{> partial}}",
+        ] {
+            assert!(Template::parse(source).is_err(), "{}", source);
+        }
+    }
+}
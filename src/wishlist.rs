@@ -0,0 +1,984 @@
+use crate::api::blueprint::BlueprintData;
+use crate::error::AppError;
+use crate::history::PriceObservation;
+use crate::notify::{self, AlertRules, Channel};
+use crate::preferences::Preferences;
+use crate::pricing::{CardRef, PriceOutcome, PriceQuote, PriceSource};
+use crate::settings::Settings;
+use crate::storage::Storage;
+use crate::tr;
+use chrono::{DateTime, Local, Utc};
+use futures::future::join_all;
+use indicatif::ProgressBar;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task;
+
+const MAX_CONCURRENT_CHECKS: usize = 15;
+
+// Wishlist usada quando nenhuma é escolhida; guarda os itens de antes das
+// wishlists com nome
+pub const DEFAULT_WISHLIST: &str = "default";
+
+fn enabled() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AlertSettings {
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+    // None usa TELEGRAM_CHAT_ID
+    #[serde(default)]
+    pub telegram_chat_id: Option<i64>,
+    // Vazio usa os canais padrão do settings.json
+    #[serde(default)]
+    pub channels: Vec<Channel>,
+}
+
+impl Default for AlertSettings {
+    fn default() -> Self {
+        AlertSettings {
+            enabled: true,
+            telegram_chat_id: None,
+            channels: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct WishlistInfo {
+    pub name: String,
+    #[serde(default)]
+    pub alerts: AlertSettings,
+}
+
+impl WishlistInfo {
+    pub fn new(name: &str) -> Self {
+        WishlistInfo {
+            name: name.to_string(),
+            alerts: AlertSettings::default(),
+        }
+    }
+}
+
+// O nome também vira nome de arquivo no armazenamento JSON
+pub fn validate_wishlist_name(name: &str) -> Result<(), AppError> {
+    let valid = !name.is_empty()
+        && name.len() <= 40
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::Config(format!(
+            "invalid wishlist name {:?}, use up to 40 letters, digits, '-' or '_'",
+            name
+        )))
+    }
+}
+
+pub fn get_wishlist(storage: &dyn Storage, name: &str) -> Result<WishlistInfo, AppError> {
+    storage
+        .wishlists()?
+        .into_iter()
+        .find(|list| list.name == name)
+        .ok_or_else(|| AppError::Config(format!("unknown wishlist {:?}, see `wishlists`", name)))
+}
+
+pub fn create_wishlist(storage: &dyn Storage, info: &WishlistInfo) -> Result<(), AppError> {
+    validate_wishlist_name(&info.name)?;
+    if get_wishlist(storage, &info.name).is_ok() {
+        return Err(AppError::Config(format!(
+            "wishlist {:?} already exists",
+            info.name
+        )));
+    }
+    storage.save_wishlist_info(info)
+}
+
+pub fn rename_wishlist(storage: &dyn Storage, from: &str, to: &str) -> Result<(), AppError> {
+    if from == DEFAULT_WISHLIST {
+        return Err(AppError::Config(
+            "the default wishlist cannot be renamed".to_string(),
+        ));
+    }
+    get_wishlist(storage, from)?;
+    validate_wishlist_name(to)?;
+    if get_wishlist(storage, to).is_ok() {
+        return Err(AppError::Config(format!(
+            "wishlist {:?} already exists",
+            to
+        )));
+    }
+    storage.rename_wishlist(from, to)
+}
+
+pub fn delete_wishlist(storage: &dyn Storage, name: &str) -> Result<(), AppError> {
+    if name == DEFAULT_WISHLIST {
+        return Err(AppError::Config(
+            "the default wishlist cannot be deleted".to_string(),
+        ));
+    }
+    get_wishlist(storage, name)?;
+    storage.delete_wishlist(name)
+}
+
+// Copia os itens selecionados para outra wishlist; retorna quantos foram
+// copiados e os que já estavam lá
+pub fn copy_items(
+    storage: &dyn Storage,
+    from: &str,
+    to: &str,
+    filter: &WishlistFilter,
+) -> Result<(usize, Vec<WishlistItem>), AppError> {
+    get_wishlist(storage, to)?;
+    let items: Vec<WishlistItem> = storage
+        .load_wishlist(from)?
+        .into_iter()
+        .filter(|item| filter.matches(item))
+        .collect();
+    let total = items.len();
+    let duplicates = add_items(storage, to, items)?;
+    Ok((total - duplicates.len(), duplicates))
+}
+
+// Uma wishlist pelo nome, ou todas
+pub fn select_wishlists(
+    storage: &dyn Storage,
+    list: Option<&str>,
+) -> Result<Vec<WishlistInfo>, AppError> {
+    match list {
+        Some(name) => Ok(vec![get_wishlist(storage, name)?]),
+        None => storage.wishlists(),
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WishlistItem {
+    pub card_name: String,
+    pub expansion_name: String,
+    pub version: String,
+    // Arquivos antigos só tinham "price", que era o preço desejado
+    #[serde(alias = "price")]
+    pub target_price: f64,
+    pub collector_number: String,
+    #[serde(default)]
+    pub blueprint_id: Option<u32>,
+    #[serde(default)]
+    pub last_price: Option<f64>,
+    #[serde(default)]
+    pub lowest_price: Option<f64>,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub added_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_checked_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub lowest_seen_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub game_id: Option<u32>,
+    // Quantas cópias a pessoa quer
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+    // None usa as preferências padrão das configurações
+    #[serde(default)]
+    pub preferences: Option<Preferences>,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+impl WishlistItem {
+    pub fn from_blueprint(blueprint: &BlueprintData, target_price: f64) -> Self {
+        WishlistItem {
+            card_name: blueprint.card_name.clone(),
+            expansion_name: blueprint.expansion_name.clone(),
+            version: blueprint.version.as_deref().unwrap_or("").to_string(),
+            target_price,
+            collector_number: blueprint.collector_number.clone(),
+            blueprint_id: Some(blueprint.blueprint_id),
+            last_price: None,
+            lowest_price: None,
+            currency: None,
+            added_at: Some(Utc::now()),
+            last_checked_at: None,
+            lowest_seen_at: None,
+            image_url: blueprint.image_url.clone(),
+            game_id: blueprint.game_id,
+            quantity: 1,
+            preferences: None,
+        }
+    }
+
+    // Registra o preço observado sem nunca mexer no preço desejado
+    pub fn record_quote(&mut self, quote: &PriceQuote) {
+        self.last_price = Some(quote.amount);
+        self.currency = Some(quote.currency.clone());
+        self.last_checked_at = Some(quote.fetched_at);
+        if self.lowest_price.is_none_or(|lowest| quote.amount < lowest) {
+            self.lowest_price = Some(quote.amount);
+            self.lowest_seen_at = Some(quote.fetched_at);
+        }
+    }
+
+    // Chave usada no histórico de preços
+    pub fn key(&self) -> String {
+        CardRef::from(self).key()
+    }
+
+    pub fn is_at_or_below_target(&self, amount: f64) -> bool {
+        amount <= self.target_price
+    }
+
+    // Quanto o último preço está acima do desejado, em porcentagem
+    // (negativo quando já está abaixo)
+    pub fn gap_percent(&self) -> Option<f64> {
+        let last_price = self.last_price?;
+        if self.target_price <= 0.0 {
+            return None;
+        }
+        Some((last_price - self.target_price) / self.target_price * 100.0)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SortKey {
+    Name,
+    Price,
+    // Mais perto do preço desejado primeiro
+    Gap,
+}
+
+// Itens ainda sem preço ficam no final
+pub fn sort_items(items: &mut [WishlistItem], key: SortKey) {
+    fn missing_last(a: Option<f64>, b: Option<f64>) -> std::cmp::Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+    let by_name = |a: &WishlistItem, b: &WishlistItem| {
+        (a.card_name.to_lowercase(), &a.expansion_name)
+            .cmp(&(b.card_name.to_lowercase(), &b.expansion_name))
+    };
+    match key {
+        SortKey::Name => items.sort_by(by_name),
+        SortKey::Price => {
+            items.sort_by(|a, b| missing_last(a.last_price, b.last_price).then(by_name(a, b)))
+        }
+        SortKey::Gap => {
+            items.sort_by(|a, b| missing_last(a.gap_percent(), b.gap_percent()).then(by_name(a, b)))
+        }
+    }
+}
+
+// Uma linha por item, usada pela CLI, pelo menu e pelo bot
+pub fn describe_item(item: &WishlistItem) -> String {
+    let version = if item.version.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", item.version)
+    };
+    let last_price = item
+        .last_price
+        .map(|price| format!("{:.2}", price))
+        .unwrap_or_else(|| "-".to_string());
+    let quantity = if item.quantity > 1 {
+        format!("{}x ", item.quantity)
+    } else {
+        String::new()
+    };
+    let preferences = item
+        .preferences
+        .as_ref()
+        .map(|preferences| format!(" ({})", preferences))
+        .unwrap_or_default();
+    tr!(
+        "item.describe",
+        quantity = quantity,
+        card = item.card_name,
+        collector_number = item.collector_number,
+        set = item.expansion_name,
+        version = version,
+        target = format!("{:.2}", item.target_price),
+        last_price = last_price,
+        preferences = preferences
+    )
+}
+
+// Seleciona itens pelo nome, expansão e/ou blueprint; campos None não filtram
+#[derive(Default, Clone)]
+pub struct WishlistFilter {
+    pub card_name: Option<String>,
+    pub expansion_name: Option<String>,
+    pub blueprint_id: Option<u32>,
+}
+
+impl WishlistFilter {
+    pub fn is_empty(&self) -> bool {
+        self.card_name.is_none() && self.expansion_name.is_none() && self.blueprint_id.is_none()
+    }
+
+    pub fn matches(&self, item: &WishlistItem) -> bool {
+        self.card_name
+            .as_ref()
+            .is_none_or(|name| item.card_name.eq_ignore_ascii_case(name))
+            && self
+                .expansion_name
+                .as_ref()
+                .is_none_or(|expansion| item.expansion_name.eq_ignore_ascii_case(expansion))
+            && self
+                .blueprint_id
+                .is_none_or(|blueprint_id| item.blueprint_id == Some(blueprint_id))
+    }
+}
+
+// Alterações de um item; None mantém o valor atual
+#[derive(Default)]
+pub struct ItemChanges {
+    pub target_price: Option<f64>,
+    pub quantity: Option<u32>,
+}
+
+impl ItemChanges {
+    pub fn apply(&self, item: &mut WishlistItem) {
+        if let Some(target_price) = self.target_price {
+            item.target_price = target_price;
+        }
+        if let Some(quantity) = self.quantity {
+            item.quantity = quantity;
+        }
+    }
+}
+
+// Valores que add, edit e import recusam em vez de salvar
+pub fn invalid_values(target_price: Option<f64>, quantity: Option<u32>) -> Option<String> {
+    if quantity == Some(0) {
+        return Some(tr!("item.invalid_quantity"));
+    }
+    match target_price {
+        Some(price) if !(price.is_finite() && price >= 0.0) => {
+            Some(tr!("item.invalid_target", price = price))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct PriceAlert {
+    pub wishlist: String,
+    pub item: WishlistItem,
+    pub quote: PriceQuote,
+}
+
+// Resultado de uma verificação, usado pela saída --json da CLI
+#[derive(Serialize, Default)]
+pub struct CheckReport {
+    pub checked: usize,
+    pub alerts: Vec<PriceAlert>,
+    pub no_listings: usize,
+    pub failures: Vec<String>,
+    // Alertas repetidos que não foram enviados de novo
+    pub suppressed: usize,
+    // Alertas guardados para o resumo diário
+    pub queued: usize,
+}
+
+impl CheckReport {
+    // Junta o relatório de outra wishlist a este
+    pub fn merge(&mut self, other: CheckReport) {
+        self.checked += other.checked;
+        self.alerts.extend(other.alerts);
+        self.no_listings += other.no_listings;
+        self.failures.extend(other.failures);
+        self.suppressed += other.suppressed;
+        self.queued += other.queued;
+    }
+}
+
+// Itens já presentes (mesmo blueprint_id) não são adicionados de novo;
+// retorna os que foram ignorados
+pub fn add_items(
+    storage: &dyn Storage,
+    list: &str,
+    items: Vec<WishlistItem>,
+) -> Result<Vec<WishlistItem>, AppError> {
+    let mut known: HashSet<u32> = storage
+        .load_wishlist(list)?
+        .iter()
+        .filter_map(|item| item.blueprint_id)
+        .collect();
+    let (added, duplicates): (Vec<WishlistItem>, Vec<WishlistItem>) = items
+        .into_iter()
+        .partition(|item| item.blueprint_id.is_none_or(|id| known.insert(id)));
+    storage.add_wishlist_items(list, &added)?;
+    Ok(duplicates)
+}
+
+// Retorna false se a carta já estava na wishlist
+pub fn add_to_wishlist(
+    storage: &dyn Storage,
+    list: &str,
+    item: WishlistItem,
+) -> Result<bool, AppError> {
+    Ok(add_items(storage, list, vec![item])?.is_empty())
+}
+
+// Novo item com o preço atual como preço desejado; None se nenhum anúncio
+// atende às preferências (as do item ou as padrão)
+pub async fn quote_item(
+    source: &dyn PriceSource,
+    blueprint: &BlueprintData,
+    quantity: u32,
+    preferences: Option<Preferences>,
+) -> Result<Option<WishlistItem>, AppError> {
+    let defaults = match &preferences {
+        Some(preferences) => preferences.clone(),
+        None => Settings::load()?.preferences,
+    };
+    let card = CardRef::from(blueprint).with_preferences(defaults, quantity);
+    let mut item = match source.fetch_quote(&card).await? {
+        PriceOutcome::Quote(quote) => {
+            let mut item = WishlistItem::from_blueprint(blueprint, quote.amount);
+            item.record_quote(&quote);
+            item
+        }
+        PriceOutcome::NoListings => return Ok(None),
+    };
+    item.quantity = quantity;
+    item.preferences = preferences;
+    Ok(Some(item))
+}
+
+pub fn load_wishlist(storage: &dyn Storage, list: &str) -> Result<Vec<WishlistItem>, AppError> {
+    storage.load_wishlist(list)
+}
+
+// Remove os itens selecionados pelo filtro e retorna os removidos
+pub fn remove_items(
+    storage: &dyn Storage,
+    list: &str,
+    filter: &WishlistFilter,
+) -> Result<Vec<WishlistItem>, AppError> {
+    let wishlist = storage.load_wishlist(list)?;
+    let (removed, kept): (Vec<WishlistItem>, Vec<WishlistItem>) =
+        wishlist.into_iter().partition(|item| filter.matches(item));
+    if !removed.is_empty() {
+        storage.save_wishlist(list, &kept)?;
+    }
+    Ok(removed)
+}
+
+// Altera os itens selecionados pelo filtro e retorna como ficaram
+pub fn edit_items(
+    storage: &dyn Storage,
+    list: &str,
+    filter: &WishlistFilter,
+    changes: &ItemChanges,
+) -> Result<Vec<WishlistItem>, AppError> {
+    let mut wishlist = storage.load_wishlist(list)?;
+    let mut edited = Vec::new();
+    for item in wishlist.iter_mut().filter(|item| filter.matches(item)) {
+        changes.apply(item);
+        edited.push(item.clone());
+    }
+    if !edited.is_empty() {
+        storage.save_wishlist(list, &wishlist)?;
+    }
+    Ok(edited)
+}
+
+// Junta itens repetidos do mesmo blueprint: fica o primeiro, com a maior
+// quantidade e o menor preço já visto entre eles
+pub fn dedup_items(items: Vec<WishlistItem>) -> (Vec<WishlistItem>, usize) {
+    let mut kept: Vec<WishlistItem> = Vec::new();
+    let mut positions: HashMap<u32, usize> = HashMap::new();
+    let mut merged = 0;
+    for item in items {
+        let Some(blueprint_id) = item.blueprint_id else {
+            kept.push(item);
+            continue;
+        };
+        match positions.get(&blueprint_id) {
+            Some(&position) => {
+                let first = &mut kept[position];
+                first.quantity = first.quantity.max(item.quantity);
+                if item
+                    .lowest_price
+                    .is_some_and(|lowest| first.lowest_price.is_none_or(|first| lowest < first))
+                {
+                    first.lowest_price = item.lowest_price;
+                    first.lowest_seen_at = item.lowest_seen_at;
+                }
+                merged += 1;
+            }
+            None => {
+                positions.insert(blueprint_id, kept.len());
+                kept.push(item);
+            }
+        }
+    }
+    (kept, merged)
+}
+
+// Retorna quantos itens repetidos foram removidos
+pub fn dedup_wishlist(storage: &dyn Storage, list: &str) -> Result<usize, AppError> {
+    let (kept, merged) = dedup_items(storage.load_wishlist(list)?);
+    if merged > 0 {
+        storage.save_wishlist(list, &kept)?;
+    }
+    Ok(merged)
+}
+
+// Consulta o preço de todos os itens em paralelo, devolvendo o resultado de
+// cada um junto com o seu índice na wishlist.
+pub(crate) async fn fetch_outcomes(
+    wishlist: &[WishlistItem],
+    source: &Arc<dyn PriceSource>,
+) -> Result<Vec<(usize, Result<PriceOutcome, AppError>)>, AppError> {
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_CHECKS));
+    let mut tasks = Vec::new();
+    let defaults = Settings::load()?.preferences;
+
+    eprintln!("{}", tr!("check.using", source = source.name()));
+    let pb = ProgressBar::new(wishlist.len() as u64);
+    for (index, item) in wishlist.iter().enumerate() {
+        let semaphore_clone = Arc::clone(&semaphore);
+        let card = CardRef::for_item(item, &defaults);
+        let source = Arc::clone(source);
+
+        let pb_clone = pb.clone();
+        let task = task::spawn(async move {
+            let _permit = semaphore_clone
+                .acquire()
+                .await
+                .expect("semaphore is never closed");
+            let outcome = source.fetch_quote(&card).await;
+
+            pb_clone.inc(1);
+            (index, outcome)
+        });
+
+        tasks.push(task);
+    }
+
+    let results = join_all(tasks).await;
+    pb.finish_with_message(tr!("check.finished"));
+
+    results
+        .into_iter()
+        .map(|result| result.map_err(AppError::from))
+        .collect()
+}
+
+// Grava os preços observados e devolve o relatório junto com a wishlist
+// atualizada; os alertas são gerados quando o preço atual é menor ou igual ao
+// preço desejado. Toda cotação é também gravada no histórico.
+fn apply_outcomes(
+    storage: &dyn Storage,
+    list: &str,
+    wishlist: &[WishlistItem],
+    outcomes: Vec<(usize, Result<PriceOutcome, AppError>)>,
+    source_name: &str,
+) -> Result<(CheckReport, Vec<WishlistItem>), AppError> {
+    let mut report = CheckReport {
+        checked: wishlist.len(),
+        ..CheckReport::default()
+    };
+
+    let mut observations = Vec::new();
+    let mut quotes = HashMap::new();
+    for (index, outcome) in outcomes {
+        let item = &wishlist[index];
+        match outcome {
+            Ok(PriceOutcome::Quote(quote)) => {
+                observations.push(PriceObservation::from_quote(
+                    &item.key(),
+                    &quote,
+                    source_name,
+                ));
+                // O preço desejado está na moeda dos preços anteriores (por
+                // exemplo reais do scraper e euros da API): não comparamos
+                match item.currency.as_deref() {
+                    Some(currency) if currency != quote.currency => {
+                        let mismatch = tr!(
+                            "check.currency_mismatch",
+                            card = item.card_name,
+                            currency = quote.currency,
+                            expected = currency
+                        );
+                        eprintln!("{}", mismatch);
+                        report.failures.push(mismatch);
+                    }
+                    _ => {
+                        quotes.insert(item.key(), quote);
+                    }
+                }
+            }
+            Ok(PriceOutcome::NoListings) => report.no_listings += 1,
+            Err(e) => {
+                // Uma falha na consulta nunca é tratada como preço
+                eprintln!(
+                    "{}",
+                    tr!("check.fetch_failed", card = item.card_name, error = e)
+                );
+                report.failures.push(format!("{}: {}", item.card_name, e));
+            }
+        }
+    }
+    print_check_summary(report.no_listings, report.failures.len());
+
+    // A consulta pode levar minutos: a wishlist é relida ao gravar, para não
+    // desfazer o que foi editado nesse meio tempo
+    let updated = storage.record_quotes(list, &quotes)?;
+    for item in &updated {
+        if let Some(quote) = quotes.get(&item.key()) {
+            if item.is_at_or_below_target(quote.amount) {
+                report.alerts.push(PriceAlert {
+                    wishlist: list.to_string(),
+                    item: item.clone(),
+                    quote: quote.clone(),
+                });
+            }
+        }
+    }
+    storage.append_observations(&observations)?;
+    Ok((report, updated))
+}
+
+// Verifica uma wishlist ou todas (None), cada uma com os seus alertas
+pub async fn check_wishlist_prices(
+    storage: &dyn Storage,
+    list: Option<&str>,
+    source: Arc<dyn PriceSource>,
+) -> Result<CheckReport, AppError> {
+    let rules = Settings::load()?.alert_rules;
+    let mut report = CheckReport::default();
+    for info in select_wishlists(storage, list)? {
+        let wishlist = storage.load_wishlist(&info.name)?;
+        let outcomes = fetch_outcomes(&wishlist, &source).await?;
+        // Os preços são gravados antes de notificar, para não perdê-los se o
+        // envio falhar
+        let (mut list_report, wishlist) =
+            apply_outcomes(storage, &info.name, &wishlist, outcomes, source.name())?;
+        if info.alerts.enabled {
+            notify_alerts(storage, &info, &wishlist, &mut list_report, &rules).await?;
+        }
+        report.merge(list_report);
+    }
+    Ok(report)
+}
+
+// Envia só os alertas novos ou mais baratos; no modo resumo eles esperam a
+// mensagem do dia. O estado só é salvo depois do envio, então um envio que
+// falhou é tentado de novo na próxima verificação.
+async fn notify_alerts(
+    storage: &dyn Storage,
+    info: &WishlistInfo,
+    wishlist: &[WishlistItem],
+    report: &mut CheckReport,
+    rules: &AlertRules,
+) -> Result<(), AppError> {
+    let mut state = storage.alert_state(&info.name)?;
+    state.forget_recovered(wishlist);
+    let now = Utc::now();
+    let (selected, suppressed) = state.select(&report.alerts, now, rules);
+    report.suppressed = suppressed;
+
+    if rules.digest {
+        report.queued = selected.len();
+        state.enqueue(selected);
+        if notify::digest_due(
+            state.last_digest_at,
+            &now.with_timezone(&Local),
+            rules.digest_hour,
+        ) {
+            let alerts: Vec<_> = state
+                .pending
+                .iter()
+                .map(|queued| queued.alert.clone())
+                .collect();
+            notify::send_alerts(&alerts, &info.alerts).await?;
+            state.pending.clear();
+            state.last_digest_at = Some(now);
+        }
+    } else {
+        // O que ficou na fila de quando o resumo estava ligado sai junto
+        let alerts: Vec<_> = state
+            .pending
+            .drain(..)
+            .map(|queued| queued.alert)
+            .chain(selected.into_iter().map(|(_, alert)| alert))
+            .collect();
+        notify::send_alerts(&alerts, &info.alerts).await?;
+    }
+    storage.save_alert_state(&state)
+}
+
+fn print_check_summary(no_listings: usize, failures: usize) {
+    if no_listings > 0 {
+        eprintln!("{}", tr!("check.no_listings", count = no_listings));
+    }
+    if failures > 0 {
+        eprintln!("{}", tr!("check.failures", count = failures));
+    }
+}
+
+pub async fn continuous_check_prices(
+    storage: &dyn Storage,
+    list: Option<&str>,
+    source: Arc<dyn PriceSource>,
+    interval: Duration,
+) -> Result<(), AppError> {
+    loop {
+        // espera `interval` após o fim de cada verificação
+        match check_wishlist_prices(storage, list, Arc::clone(&source)).await {
+            Ok(_) => {}
+            Err(e) if e.is_retryable() => eprintln!("{}", tr!("check.retrying", error = e)),
+            Err(e) => return Err(e),
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+// Atualiza os preços observados sem enviar alertas
+pub async fn sync_prices(
+    storage: &dyn Storage,
+    list: Option<&str>,
+    source: Arc<dyn PriceSource>,
+) -> Result<(), AppError> {
+    for info in select_wishlists(storage, list)? {
+        let wishlist = storage.load_wishlist(&info.name)?;
+        let outcomes = fetch_outcomes(&wishlist, &source).await?;
+        apply_outcomes(storage, &info.name, &wishlist, outcomes, source.name())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pricing::FixturePriceSource;
+    use crate::storage::SqliteStorage;
+    use async_trait::async_trait;
+
+    #[test]
+    fn old_price_field_becomes_target_price() {
+        let old = r#"{
+            "card_name": "Lightning Bolt",
+            "expansion_name": "Magic 2011",
+            "version": "",
+            "price": 2.5,
+            "collector_number": "146"
+        }"#;
+
+        let item: WishlistItem = serde_json::from_str(old).unwrap();
+        assert_eq!(item.target_price, 2.5);
+        assert_eq!(item.last_price, None);
+        assert_eq!(item.blueprint_id, None);
+        assert_eq!(item.quantity, 1);
+    }
+
+    fn item(card_name: &str, blueprint_id: u32, target: f64, last: Option<f64>) -> WishlistItem {
+        let blueprint = BlueprintData {
+            blueprint_id,
+            card_name: card_name.to_string(),
+            expansion_name: "Alpha".to_string(),
+            ..BlueprintData::default()
+        };
+        let mut item = WishlistItem::from_blueprint(&blueprint, target);
+        item.last_price = last;
+        item
+    }
+
+    #[test]
+    fn items_sort_by_name_price_and_gap() {
+        let mut items = vec![
+            item("sol Ring", 1, 1.0, Some(3.0)),
+            item("Black Lotus", 2, 10000.0, Some(12000.0)),
+            item("Mox Pearl", 3, 1000.0, None),
+        ];
+
+        sort_items(&mut items, SortKey::Name);
+        let names: Vec<&str> = items.iter().map(|i| i.card_name.as_str()).collect();
+        assert_eq!(names, vec!["Black Lotus", "Mox Pearl", "sol Ring"]);
+
+        sort_items(&mut items, SortKey::Price);
+        assert_eq!(items[0].card_name, "sol Ring");
+        assert_eq!(items[2].card_name, "Mox Pearl");
+
+        // Lotus está 20% acima do desejado, Sol Ring 200%
+        sort_items(&mut items, SortKey::Gap);
+        assert_eq!(items[0].card_name, "Black Lotus");
+        assert_eq!(items[0].gap_percent(), Some(20.0));
+    }
+
+    #[test]
+    fn duplicates_keep_the_first_item() {
+        let mut second = item("Sol Ring", 1, 5.0, None);
+        second.quantity = 4;
+        second.lowest_price = Some(2.0);
+        let items = vec![
+            item("Sol Ring", 1, 1.0, None),
+            item("Mox", 2, 1.0, None),
+            second,
+        ];
+
+        let (kept, merged) = dedup_items(items);
+
+        assert_eq!(merged, 1);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].target_price, 1.0);
+        assert_eq!(kept[0].quantity, 4);
+        assert_eq!(kept[0].lowest_price, Some(2.0));
+    }
+
+    #[tokio::test]
+    async fn checks_alert_at_the_target_and_keep_prices_on_failures() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        let mut info = WishlistInfo::new(DEFAULT_WISHLIST);
+        info.alerts.enabled = false;
+        storage.save_wishlist_info(&info).unwrap();
+        let mut in_reais = item("Tutor", 15, 5.0, Some(30.0));
+        in_reais.currency = Some("BRL".to_string());
+        storage
+            .add_wishlist_items(
+                DEFAULT_WISHLIST,
+                &[
+                    item("Sol Ring", 11, 5.0, None),
+                    item("Mox", 12, 5.0, None),
+                    item("Lotus", 13, 5.0, Some(9.0)),
+                    item("Bolt", 14, 5.0, Some(7.0)),
+                    in_reais,
+                ],
+            )
+            .unwrap();
+        // 14 não tem cotação: a consulta falha; 15 troca de moeda
+        let quotes = HashMap::from([
+            ("11".to_string(), Some(PriceQuote::new(5.0, "EUR"))),
+            ("12".to_string(), Some(PriceQuote::new(5.01, "EUR"))),
+            ("13".to_string(), None),
+            ("15".to_string(), Some(PriceQuote::new(1.0, "EUR"))),
+        ]);
+        let source: Arc<dyn PriceSource> = Arc::new(FixturePriceSource::new(quotes));
+
+        let report = check_wishlist_prices(&storage, None, source).await.unwrap();
+
+        assert_eq!(report.checked, 5);
+        assert_eq!(report.alerts.len(), 1);
+        assert_eq!(report.alerts[0].item.card_name, "Sol Ring");
+        assert_eq!(report.no_listings, 1);
+        assert_eq!(report.failures.len(), 2);
+        assert!(report.failures.iter().any(|f| f.starts_with("Bolt")));
+        assert!(report.failures.iter().any(|f| f.starts_with("Tutor")));
+
+        let items = storage.load_wishlist(DEFAULT_WISHLIST).unwrap();
+        let prices: Vec<Option<f64>> = items.iter().map(|i| i.last_price).collect();
+        assert_eq!(
+            prices,
+            vec![Some(5.0), Some(5.01), Some(9.0), Some(7.0), Some(30.0)]
+        );
+        assert_eq!(storage.observations("12", None).unwrap().len(), 1);
+        assert!(storage.observations("14", None).unwrap().is_empty());
+    }
+
+    // Demora a responder, como uma consulta de verdade
+    struct SlowSource(FixturePriceSource);
+
+    #[async_trait]
+    impl PriceSource for SlowSource {
+        fn name(&self) -> &'static str {
+            "fixture"
+        }
+
+        async fn fetch_quote(&self, card: &CardRef) -> Result<PriceOutcome, AppError> {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.0.fetch_quote(card).await
+        }
+    }
+
+    #[tokio::test]
+    async fn edits_made_during_a_check_are_kept() {
+        let storage = SqliteStorage::open_in_memory().unwrap();
+        storage
+            .add_wishlist_items(
+                DEFAULT_WISHLIST,
+                &[item("Sol Ring", 11, 1.0, None), item("Mox", 12, 1.0, None)],
+            )
+            .unwrap();
+        let quotes = HashMap::from([
+            ("11".to_string(), Some(PriceQuote::new(3.0, "EUR"))),
+            ("12".to_string(), Some(PriceQuote::new(4.0, "EUR"))),
+        ]);
+        let source: Arc<dyn PriceSource> = Arc::new(SlowSource(FixturePriceSource::new(quotes)));
+
+        let edit = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            let by_id = |blueprint_id| WishlistFilter {
+                blueprint_id: Some(blueprint_id),
+                ..WishlistFilter::default()
+            };
+            let changes = ItemChanges {
+                target_price: Some(2.0),
+                ..ItemChanges::default()
+            };
+            edit_items(&storage, DEFAULT_WISHLIST, &by_id(11), &changes).unwrap();
+            remove_items(&storage, DEFAULT_WISHLIST, &by_id(12)).unwrap();
+            storage
+                .add_wishlist_items(DEFAULT_WISHLIST, &[item("Lotus", 13, 1.0, None)])
+                .unwrap();
+        };
+        let (report, _) = tokio::join!(sync_prices(&storage, None, source), edit);
+        report.unwrap();
+
+        let items = storage.load_wishlist(DEFAULT_WISHLIST).unwrap();
+        let names: Vec<&str> = items.iter().map(|i| i.card_name.as_str()).collect();
+        assert_eq!(names, vec!["Sol Ring", "Lotus"]);
+        assert_eq!(items[0].target_price, 2.0);
+        assert_eq!(items[0].last_price, Some(3.0));
+        assert_eq!(items[1].last_price, None);
+    }
+
+    #[test]
+    fn recording_quotes_never_moves_the_target() {
+        let old = r#"{"card_name":"Sol Ring","expansion_name":"Commander Masters",
+            "version":"","price":3.0,"collector_number":"1"}"#;
+        let mut item: WishlistItem = serde_json::from_str(old).unwrap();
+
+        item.record_quote(&PriceQuote::new(2.0, "EUR"));
+        item.record_quote(&PriceQuote::new(4.0, "EUR"));
+
+        assert_eq!(item.target_price, 3.0);
+        assert_eq!(item.last_price, Some(4.0));
+        assert_eq!(item.lowest_price, Some(2.0));
+        assert!(item.is_at_or_below_target(3.0));
+        assert!(!item.is_at_or_below_target(3.01));
+    }
+
+    #[test]
+    fn zero_quantities_and_negative_targets_are_invalid() {
+        assert_eq!(invalid_values(Some(0.0), Some(1)), None);
+        assert!(invalid_values(None, Some(0)).is_some());
+        assert!(invalid_values(Some(-1.0), None).is_some());
+        assert!(invalid_values(Some(f64::NAN), None).is_some());
+
+        let mut item = item("Sol Ring", 11, 1.0, None);
+        let changes = ItemChanges {
+            quantity: Some(3),
+            ..ItemChanges::default()
+        };
+        changes.apply(&mut item);
+        assert_eq!(item.quantity, 3);
+    }
+}